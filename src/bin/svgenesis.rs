@@ -0,0 +1,213 @@
+//! Renders seeds to SVG and PNG files without going through a ledger.
+//!
+//! Prints a JSON line per seed with its layers and the hash of its fingerprint, the same hash the component uses to
+//! detect duplicate images. It only uses the generator, so it builds without the blueprint.
+
+use resvg::{tiny_skia, usvg};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use svgenesis::layers::DefaultWeights;
+use svgenesis::nft_generator::{generate_nft_image_data_with_version, GeneratorVersion};
+use svgenesis::preview::{decode_seed, fingerprint_hash};
+
+const USAGE: &str = "Usage: svgenesis [OPTIONS] [SEED]...
+
+Renders hex-encoded seeds to SVG and PNG files and prints a JSON line per seed.
+
+Options:
+  --seed-file <FILE>            Read seeds from a file, one per line. Empty lines and lines starting with # are skipped
+  --out <DIR>                   Directory to write the images to [default: images]
+  --png-size <PIXELS>           Width and height of the PNGs [default: 1000]
+  --no-png                      Only write SVGs
+  --generator-version <VERSION> Generator version to render with [default: the current version]
+  -h, --help                    Print this help";
+
+struct Options {
+    seeds: Vec<String>,
+    out_dir: PathBuf,
+    png_size: Option<u32>,
+    generator_version: GeneratorVersion,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(error) => {
+            eprintln!("{error}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(error) = fs::create_dir_all(&options.out_dir) {
+        eprintln!("Failed to create {}: {error}", options.out_dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    // Keep going when a single seed fails, but report it in the exit code
+    let mut exit_code = ExitCode::SUCCESS;
+    // The seed every image was written for, by the hash of its fingerprint
+    let mut written_images = HashMap::new();
+    for seed in &options.seeds {
+        match render_seed(seed, &options, &mut written_images) {
+            Ok(json_line) => println!("{json_line}"),
+            Err(error) => {
+                eprintln!("{seed}: {error}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    exit_code
+}
+
+/// Parses the command-line arguments. Returns `None` if the help was requested.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        seeds: vec![],
+        out_dir: PathBuf::from("images"),
+        png_size: Some(1000),
+        generator_version: GeneratorVersion::CURRENT,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{name} requires a value"));
+
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--seed-file" => {
+                let path = value("--seed-file")?;
+                let contents = fs::read_to_string(&path)
+                    .map_err(|error| format!("Failed to read {path}: {error}"))?;
+                options.seeds.extend(
+                    contents
+                        .lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                        .map(str::to_string),
+                );
+            }
+            "--out" => options.out_dir = PathBuf::from(value("--out")?),
+            "--png-size" => {
+                let png_size = value("--png-size")?;
+                options.png_size = Some(
+                    png_size
+                        .parse()
+                        .ok()
+                        .filter(|png_size| *png_size > 0)
+                        .ok_or(format!("Invalid PNG size {png_size}"))?,
+                );
+            }
+            "--no-png" => options.png_size = None,
+            "--generator-version" => {
+                let generator_version = value("--generator-version")?;
+                options.generator_version = generator_version
+                    .parse()
+                    .ok()
+                    .and_then(GeneratorVersion::from_u32)
+                    .ok_or(format!("Unknown generator version {generator_version}"))?;
+            }
+            _ if arg.starts_with('-') => return Err(format!("Unknown option {arg}")),
+            _ => options.seeds.push(arg),
+        }
+    }
+
+    if options.seeds.is_empty() {
+        return Err("No seeds provided".to_string());
+    }
+
+    Ok(Some(options))
+}
+
+/// Writes the images of a seed and returns its JSON line
+///
+/// Fails if another seed of the batch already produced the same image, which the component would reject as well.
+fn render_seed(
+    seed_hex: &str,
+    options: &Options,
+    written_images: &mut HashMap<String, String>,
+) -> Result<String, String> {
+    let seed = decode_seed(seed_hex)?;
+
+    let (nft_image_data, layers) =
+        generate_nft_image_data_with_version(&seed, options.generator_version, &DefaultWeights);
+    let fingerprint_hash = fingerprint_hash(&nft_image_data, &layers);
+
+    if let Some(other_seed_hex) = written_images.get(&fingerprint_hash) {
+        if *other_seed_hex != seed_hex {
+            return Err(format!("Same image as seed {other_seed_hex}"));
+        }
+    }
+    written_images.insert(fingerprint_hash.clone(), seed_hex.to_string());
+
+    // Name the files after the hash of the fingerprint, so the images of different seeds never overwrite each other.
+    // Seeds themselves are too long for a file name.
+    let file_name = &fingerprint_hash;
+    let svg_path = options.out_dir.join(format!("{file_name}.svg"));
+    fs::write(&svg_path, &nft_image_data)
+        .map_err(|error| format!("Failed to write {}: {error}", svg_path.display()))?;
+
+    let png_path = match options.png_size {
+        Some(png_size) => {
+            let png_path = options.out_dir.join(format!("{file_name}.png"));
+            write_png(&nft_image_data, png_size, &png_path)?;
+            Some(png_path)
+        }
+        None => None,
+    };
+
+    let layers_json: Vec<String> = layers.iter().map(|layer| json_string(layer)).collect();
+    Ok(format!(
+        "{{\"seed\":{},\"generator_version\":{},\"layers\":[{}],\"hash\":{},\"svg\":{},\"png\":{}}}",
+        json_string(seed_hex),
+        options.generator_version.as_u32(),
+        layers_json.join(","),
+        json_string(&fingerprint_hash),
+        json_string(&svg_path.display().to_string()),
+        png_path.map_or("null".to_string(), |png_path| json_string(
+            &png_path.display().to_string()
+        )),
+    ))
+}
+
+/// Rasterizes the SVG data to a square PNG
+fn write_png(nft_image_data: &str, png_size: u32, path: &Path) -> Result<(), String> {
+    let tree = usvg::Tree::from_str(nft_image_data, &usvg::Options::default())
+        .map_err(|error| format!("Failed to parse SVG: {error}"))?;
+
+    let mut pixmap =
+        tiny_skia::Pixmap::new(png_size, png_size).ok_or(format!("Invalid PNG size {png_size}"))?;
+    let scale = png_size as f32 / tree.size().width();
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap
+        .save_png(path)
+        .map_err(|error| format!("Failed to write {}: {error}", path.display()))
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            character if character.is_control() => {
+                json.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => json.push(character),
+        }
+    }
+    json.push('"');
+
+    json
+}
@@ -0,0 +1,128 @@
+/// The characters of the base64 alphabet, in order
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Turns SVG data into a data URI that can be used as a `key_image_url`.
+///
+/// Returns the smallest of the percent-encoded and the base64 variant, preferring the readable percent-encoded one.
+pub fn svg_data_uri(svg_data: &str) -> String {
+    let percent_encoded_data_uri = percent_encoded_svg_data_uri(svg_data);
+    let base64_data_uri = base64_svg_data_uri(svg_data);
+
+    if base64_data_uri.len() < percent_encoded_data_uri.len() {
+        base64_data_uri
+    } else {
+        percent_encoded_data_uri
+    }
+}
+
+/// Turns SVG data into a data URI that only escapes the characters a URI can't contain: `#`, `%`, `<`, `>`, quotes,
+/// control characters like newlines, and anything that isn't ASCII
+pub fn percent_encoded_svg_data_uri(svg_data: &str) -> String {
+    let mut data_uri = String::with_capacity(svg_data.len() + 32);
+    data_uri.push_str("data:image/svg+xml,");
+
+    for byte in svg_data.bytes() {
+        match byte {
+            b'#' | b'%' | b'<' | b'>' | b'"' | b'\'' => push_escaped(&mut data_uri, byte),
+            byte if byte.is_ascii_control() || !byte.is_ascii() => {
+                push_escaped(&mut data_uri, byte)
+            }
+            byte => data_uri.push(byte as char),
+        }
+    }
+
+    data_uri
+}
+
+/// Turns SVG data into a base64-encoded data URI
+pub fn base64_svg_data_uri(svg_data: &str) -> String {
+    let bytes = svg_data.as_bytes();
+    let mut data_uri = String::with_capacity(bytes.len() / 3 * 4 + 32);
+    data_uri.push_str("data:image/svg+xml;base64,");
+
+    // Every 3 bytes become 4 characters, and the last group is padded with =
+    for chunk in bytes.chunks(3) {
+        let group = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (group >> (18 - 6 * index)) & 0b111111;
+                data_uri.push(BASE64_ALPHABET[sextet as usize] as char);
+            } else {
+                data_uri.push('=');
+            }
+        }
+    }
+
+    data_uri
+}
+
+/// Decodes an SVG data URI back into the SVG data. Besides the forms above, it decodes fully percent-encoded data URIs,
+/// which older NFTs use.
+///
+/// Returns `None` if it isn't a valid SVG data URI
+pub fn decode_svg_data_uri(data_uri: &str) -> Option<String> {
+    if let Some(base64_data) = data_uri.strip_prefix("data:image/svg+xml;base64,") {
+        return String::from_utf8(decode_base64(base64_data)?).ok();
+    }
+
+    let percent_encoded_data = data_uri.strip_prefix("data:image/svg+xml,")?;
+    let mut bytes = Vec::with_capacity(percent_encoded_data.len());
+    let mut encoded_bytes = percent_encoded_data.bytes();
+    while let Some(byte) = encoded_bytes.next() {
+        match byte {
+            b'%' => {
+                let high = hex_value(encoded_bytes.next()?)?;
+                let low = hex_value(encoded_bytes.next()?)?;
+                bytes.push(high << 4 | low);
+            }
+            byte => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+fn decode_base64(base64_data: &str) -> Option<Vec<u8>> {
+    let base64_data = base64_data.as_bytes();
+    if base64_data.len() % 4 != 0 {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(base64_data.len() / 4 * 3);
+    for chunk in base64_data.chunks(4) {
+        let padding = chunk.iter().rev().take_while(|byte| **byte == b'=').count();
+        if padding > 2 {
+            return None;
+        }
+
+        let mut group: u32 = 0;
+        for byte in &chunk[..4 - padding] {
+            let sextet = BASE64_ALPHABET
+                .iter()
+                .position(|character| character == byte)?;
+            group = group << 6 | sextet as u32;
+        }
+        group <<= 6 * padding;
+
+        bytes.extend_from_slice(&group.to_be_bytes()[1..4 - padding]);
+    }
+
+    Some(bytes)
+}
+
+fn hex_value(character: u8) -> Option<u8> {
+    match character {
+        b'0'..=b'9' => Some(character - b'0'),
+        b'a'..=b'f' => Some(character - b'a' + 10),
+        b'A'..=b'F' => Some(character - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn push_escaped(data_uri: &mut String, byte: u8) {
+    data_uri.push_str(&format!("%{byte:02X}"));
+}
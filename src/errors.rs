@@ -0,0 +1,139 @@
+use scrypto::prelude::*;
+use std::fmt;
+
+/// Everything that can go wrong when calling an SVGenesis component.
+///
+/// Component methods panic with the message of this error, so callers can match on `SVGenesisError::to_string()`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SVGenesisError {
+    NegativeMintPrice,
+    ZeroMaxSupply,
+    EmptySeed,
+    InvalidSeedLength,
+    SeedUsed,
+    DuplicateImage,
+    NoSeeds,
+    DuplicateSeedInBatch,
+    DuplicateImageInBatch,
+    InvalidBatchSeed {
+        index: usize,
+        error: Box<SVGenesisError>,
+    },
+    MintClosed,
+    SoldOut,
+    SupplyExhausted {
+        remaining: u64,
+    },
+    CannotReopenMint,
+    WrongPaymentResource,
+    InsufficientPayment {
+        mint_price: Decimal,
+    },
+    RandomnessNotEnabled,
+    NoPendingMint,
+    NftIdTooLarge,
+    NoUniqueImage {
+        error: Box<SVGenesisError>,
+    },
+    NotAnSVGenesisNft,
+    InvalidRerollAmount,
+    ImagePending,
+    NonIntegerNftId,
+    InvalidSVGData,
+    UnknownLayer,
+    LayerWeightsLocked,
+    NoBackgroundsLeft,
+    NoElementsLeft,
+    LayerWeightTooHigh {
+        max_weight: u32,
+    },
+    UnsupportedGeneratorVersion {
+        generator_version: u32,
+    },
+}
+
+impl SVGenesisError {
+    /// Aborts the transaction with this error
+    pub fn panic(&self) -> ! {
+        panic!("{}", self)
+    }
+}
+
+impl fmt::Display for SVGenesisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SVGenesisError::NegativeMintPrice => write!(f, "Mint price can't be negative!"),
+            SVGenesisError::ZeroMaxSupply => write!(f, "Max supply must be at least 1!"),
+            SVGenesisError::EmptySeed => write!(f, "Seed can't be empty!"),
+            SVGenesisError::InvalidSeedLength => {
+                write!(f, "Seed length must be a multiple of 4!")
+            }
+            SVGenesisError::SeedUsed => write!(f, "Seed was already used! Try another one :)"),
+            SVGenesisError::DuplicateImage => write!(f, "This image already exists!"),
+            SVGenesisError::NoSeeds => write!(f, "No seeds were provided!"),
+            SVGenesisError::DuplicateSeedInBatch => {
+                write!(f, "Seed occurs more than once in this batch!")
+            }
+            SVGenesisError::DuplicateImageInBatch => {
+                write!(f, "This image already exists in this batch!")
+            }
+            SVGenesisError::InvalidBatchSeed { index, error } => {
+                write!(f, "Seed at index {}: {}", index, error)
+            }
+            SVGenesisError::MintClosed => write!(f, "Minting is closed!"),
+            SVGenesisError::SoldOut => write!(f, "The collection is sold out!"),
+            SVGenesisError::SupplyExhausted { remaining } => {
+                write!(f, "Not enough supply left! Only {} NFTs remain.", remaining)
+            }
+            SVGenesisError::CannotReopenMint => {
+                write!(f, "The supply is exhausted, the mint can't be reopened!")
+            }
+            SVGenesisError::WrongPaymentResource => write!(f, "Payment must be made in XRD!"),
+            SVGenesisError::InsufficientPayment { mint_price } => write!(
+                f,
+                "Insufficient payment! The mint price is {} XRD per NFT.",
+                mint_price
+            ),
+            SVGenesisError::RandomnessNotEnabled => {
+                write!(f, "Minting with on-ledger randomness is not enabled!")
+            }
+            SVGenesisError::NoPendingMint => write!(f, "No pending mint found for this key!"),
+            SVGenesisError::NftIdTooLarge => write!(f, "NFT id does not fit in a request key!"),
+            SVGenesisError::NoUniqueImage { error } => {
+                write!(f, "Could not generate a unique image: {}", error)
+            }
+            SVGenesisError::NotAnSVGenesisNft => write!(f, "This is not an SVGenesis NFT!"),
+            SVGenesisError::InvalidRerollAmount => {
+                write!(f, "Only one NFT can be rerolled at a time!")
+            }
+            SVGenesisError::ImagePending => write!(f, "This NFT is still waiting for its image!"),
+            SVGenesisError::NonIntegerNftId => write!(f, "SVGenesis NFTs have integer ids!"),
+            SVGenesisError::InvalidSVGData => write!(f, "Could not decode the SVG data!"),
+            SVGenesisError::UnknownLayer => write!(f, "This layer does not exist!"),
+            SVGenesisError::LayerWeightsLocked => {
+                write!(f, "Layer weights can't be changed in compact storage mode!")
+            }
+            SVGenesisError::NoBackgroundsLeft => {
+                write!(f, "At least one background must stay enabled!")
+            }
+            SVGenesisError::NoElementsLeft => {
+                write!(f, "At least one big or small element must stay enabled!")
+            }
+            SVGenesisError::LayerWeightTooHigh { max_weight } => {
+                write!(f, "Layer weight can't be higher than {}!", max_weight)
+            }
+            SVGenesisError::UnsupportedGeneratorVersion { generator_version } => write!(
+                f,
+                "Generator version {} is not supported by this component!",
+                generator_version
+            ),
+        }
+    }
+}
+
+/// Aborts the transaction with the error if the condition does not hold
+pub fn ensure(condition: bool, error: SVGenesisError) {
+    if !condition {
+        error.panic();
+    }
+}
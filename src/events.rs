@@ -1,3 +1,4 @@
+use crate::types::SVGenesisTrait;
 use scrypto::prelude::*;
 
 #[derive(ScryptoSbor, ScryptoEvent)]
@@ -5,4 +6,5 @@ pub struct Generation {
     pub key_image_url: Url,
     pub seed_lossy: String,
     pub non_fungible_local_id: NonFungibleLocalId,
+    pub traits: Vec<SVGenesisTrait>,
 }
@@ -1,4 +1,4 @@
-use random::Random;
+use crate::rng::Random;
 
 // A relatively simple implementation of HSL so we can randomly
 // generate colors and have some proper influence on how they are
@@ -19,7 +19,7 @@ pub enum ColorMode {
     Tone,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct HSL {
     pub hue: i16,       // 0-360
     pub saturation: i8, // 0-100
@@ -221,6 +221,27 @@ impl HSL {
         )
     }
 
+    /// Returns the HSL color formatted as the shortest string that means the same as `as_string()`:
+    ///
+    /// hsl(hue,saturation%,lightness%) or hsla(hue,saturation%,lightness%,.opacity)
+    pub fn as_short_string(&self) -> String {
+        match self.opacity {
+            100 => format!("hsl({},{}%,{}%)", self.hue, self.saturation, self.lightness),
+            _ => {
+                let opacity_digits = format!("{:0>2}", self.opacity);
+                let opacity_string = match opacity_digits.trim_end_matches('0') {
+                    "" => "0".to_string(),
+                    digits => format!(".{digits}"),
+                };
+
+                format!(
+                    "hsla({},{}%,{}%,{})",
+                    self.hue, self.saturation, self.lightness, opacity_string
+                )
+            }
+        }
+    }
+
     /// Derives a color close to this color
     ///
     /// Returns the derived color
@@ -264,4 +285,36 @@ impl HSL {
         // Return the new color
         Self::new(new_hue, new_saturation, new_lightness, self.opacity)
     }
+
+    /// Returns a human-readable name for the color, like "Light Blue" or "Gray"
+    pub fn color_name(&self) -> String {
+        if self.lightness >= 95 {
+            return "White".to_string();
+        } else if self.lightness <= 8 {
+            return "Black".to_string();
+        }
+
+        let name = if self.saturation <= 12 {
+            "Gray"
+        } else {
+            match self.hue.rem_euclid(360) {
+                0..=14 | 335..=359 => "Red",
+                15..=44 => "Orange",
+                45..=69 => "Yellow",
+                70..=164 => "Green",
+                165..=194 => "Cyan",
+                195..=254 => "Blue",
+                255..=289 => "Purple",
+                _ => "Pink",
+            }
+        };
+
+        if self.lightness >= 70 {
+            format!("Light {name}")
+        } else if self.lightness <= 30 {
+            format!("Dark {name}")
+        } else {
+            name.to_string()
+        }
+    }
 }
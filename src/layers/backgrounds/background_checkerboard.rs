@@ -1,15 +1,30 @@
-use std::any::Any;
-
-use crate::layers::Layer;
+use crate::layers::{Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
 use crate::{hsl::*, layers::overlays};
-use random::Random;
-use svg::node::element::{path::Data, Element, Path, Rectangle};
+use svg::node::element::{Element, Path, Rectangle};
 
 pub struct BackgroundCheckerboard;
 
 impl Layer for BackgroundCheckerboard {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundCheckerboard"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        5
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the rectangle that will be our background
         let mut rectangle = Rectangle::new().set("width", "100%").set("height", "100%");
 
@@ -79,8 +94,8 @@ impl Layer for BackgroundCheckerboard {
                 let color4 = base_color.unwrap().derive_similar_color(random);
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
-                    gradient_definition(random, Some(45), color3, color4),
+                    gradient_definition(random, ids, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color3, color4),
                 )
             } else {
                 // Pick a random color
@@ -94,8 +109,8 @@ impl Layer for BackgroundCheckerboard {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                 )
             };
 
@@ -111,7 +126,9 @@ impl Layer for BackgroundCheckerboard {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
-        vec![overlays::overlay_triangle::OverlayTriangle.type_id()]
+    fn exclusions(&self) -> Vec<Exclusion> {
+        vec![Exclusion::Layer(
+            overlays::overlay_triangle::OverlayTriangle.id(),
+        )]
     }
 }
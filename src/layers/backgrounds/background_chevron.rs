@@ -1,14 +1,30 @@
-use std::any::Any;
-
-use crate::layers::{overlays, Layer};
+use crate::layers::{overlays, Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
+use crate::utils::{DefinitionKind, IdAllocator};
 use crate::{hsl::*, layers::big_elements};
-use random::Random;
 use svg::node::element::{Definitions, Element, Pattern, Polygon, Rectangle};
 
 pub struct BackgroundChevron;
 
 impl Layer for BackgroundChevron {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundChevron"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        5
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the colors for the pattern, we ignore one color as that's a bit easier
         // with the color generation methods.
         let (color1, color2, _) = if base_color.is_some() {
@@ -64,7 +80,7 @@ impl Layer for BackgroundChevron {
             .set("points", "0,0 100,100, 200,0 200,100 100,200, 0,100")
             .set("fill", color2);
 
-        let pattern_name = format!("pat{}", random.in_range::<u16>(0, 65535));
+        let pattern_name = ids.allocate(random, DefinitionKind::Pattern);
         let pattern = Pattern::new()
             .set("id", pattern_name.clone())
             .set("patternTransform", format!("rotate({rotate_amount})"))
@@ -85,10 +101,10 @@ impl Layer for BackgroundChevron {
         vec![defs.into(), background.into()]
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            big_elements::big_element_zig_zag::BigElementZigZag.type_id(),
-            overlays::overlay_triangle::OverlayTriangle.type_id(),
+            Exclusion::Layer(big_elements::big_element_zig_zag::BigElementZigZag.id()),
+            Exclusion::Layer(overlays::overlay_triangle::OverlayTriangle.id()),
         ]
     }
 }
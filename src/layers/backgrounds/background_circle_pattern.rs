@@ -1,16 +1,31 @@
-use std::any::Any;
-
 use crate::hsl::*;
 use crate::layers::small_elements;
-use crate::layers::Layer;
+use crate::layers::{Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
-use random::Random;
 use svg::node::element::{Circle, Definitions, Element, Pattern, Rectangle};
 
 pub struct BackgroundCirclePattern;
 
 impl Layer for BackgroundCirclePattern {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundCirclePattern"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        5
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the rectangle that will be our background color
         let mut pattern_rectangle = Rectangle::new().set("width", "100%").set("height", "100%");
 
@@ -18,7 +33,7 @@ impl Layer for BackgroundCirclePattern {
         let mut circle = Circle::new().set("cx", 100).set("cy", 100).set("r", 100);
 
         // Generate the pattern
-        let pattern_name = format!("pat{}", random.in_range::<u16>(0, 65535));
+        let pattern_name = ids.allocate(random, DefinitionKind::Pattern);
         let mut pattern = Pattern::new()
             .set("id", pattern_name.clone())
             .set("patternUnits", "userSpaceOnUse")
@@ -73,7 +88,7 @@ impl Layer for BackgroundCirclePattern {
                 let rect_color = base_color.unwrap().derive_similar_color(random).as_string();
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color1, color2),
                     rect_color,
                 )
             } else {
@@ -88,7 +103,7 @@ impl Layer for BackgroundCirclePattern {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                     HSL::new_random(random, color_mode, 100).as_string(),
                 )
             };
@@ -109,12 +124,18 @@ impl Layer for BackgroundCirclePattern {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            small_elements::small_element_circle::SmallElementCircle.type_id(),
-            small_elements::small_element_circle_shadow::SmallElementCircleShadow.type_id(),
-            small_elements::small_element_double_circle::SmallElementDoubleCircle.type_id(),
-            small_elements::small_element_split_circle::SmallElementSplitCircle.type_id(),
+            Exclusion::Layer(small_elements::small_element_circle::SmallElementCircle.id()),
+            Exclusion::Layer(
+                small_elements::small_element_circle_shadow::SmallElementCircleShadow.id(),
+            ),
+            Exclusion::Layer(
+                small_elements::small_element_double_circle::SmallElementDoubleCircle.id(),
+            ),
+            Exclusion::Layer(
+                small_elements::small_element_split_circle::SmallElementSplitCircle.id(),
+            ),
         ]
     }
 }
@@ -1,15 +1,33 @@
-use std::any::Any;
-
 use crate::hsl::*;
 use crate::layers::big_elements;
-use crate::{layers::Layer, utils::*};
-use random::Random;
+use crate::rng::Random;
+use crate::{
+    layers::{Exclusion, Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Polygon};
 
 pub struct BackgroundDiagonalSplit;
 
 impl Layer for BackgroundDiagonalSplit {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundDiagonalSplit"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the two triangles that will make up the diagonal split background
         let mut triangle1 = Polygon::new().set("points", "0, 0, 1000, 1000, 0, 1000");
         let mut triangle2 = Polygon::new().set("points", "1000, 1000, 1000, 0, 0, 0");
@@ -62,8 +80,8 @@ impl Layer for BackgroundDiagonalSplit {
                 let color4 = base_color.unwrap().derive_similar_color(random);
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
-                    gradient_definition(random, Some(45), color3, color4),
+                    gradient_definition(random, ids, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color3, color4),
                 )
             } else {
                 // Pick a random color
@@ -77,8 +95,8 @@ impl Layer for BackgroundDiagonalSplit {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                 )
             };
 
@@ -95,12 +113,14 @@ impl Layer for BackgroundDiagonalSplit {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            big_elements::big_element_zig_zag::BigElementZigZag.type_id(),
-            big_elements::big_element_pill::BigElementPill.type_id(),
-            big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.type_id(),
-            big_elements::big_element_pill_ball::BigElementPillBall.type_id(),
+            Exclusion::Layer(big_elements::big_element_zig_zag::BigElementZigZag.id()),
+            Exclusion::Layer(big_elements::big_element_pill::BigElementPill.id()),
+            Exclusion::Layer(
+                big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.id(),
+            ),
+            Exclusion::Layer(big_elements::big_element_pill_ball::BigElementPillBall.id()),
         ]
     }
 }
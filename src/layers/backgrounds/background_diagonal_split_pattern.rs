@@ -1,15 +1,30 @@
-use std::any::Any;
-
-use crate::layers::Layer;
+use crate::layers::{Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
 use crate::{hsl::*, layers::small_elements};
-use random::Random;
 use svg::node::element::{Definitions, Element, Pattern, Polygon, Rectangle};
 
 pub struct BackgroundDiagonalSplitPattern;
 
 impl Layer for BackgroundDiagonalSplitPattern {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundDiagonalSplitPattern"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        5
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the rectangle that will be our background
         let mut pattern_rectangle = Rectangle::new().set("width", "100%").set("height", "100%");
 
@@ -17,7 +32,7 @@ impl Layer for BackgroundDiagonalSplitPattern {
         let mut polygon = Polygon::new().set("points", "0,0 250,250 0,250");
 
         // Generate the pattern
-        let pattern_name = format!("pat{}", random.in_range::<u16>(0, 65535));
+        let pattern_name = ids.allocate(random, DefinitionKind::Pattern);
         let mut pattern = Pattern::new()
             .set("id", pattern_name.clone())
             .set("patternUnits", "userSpaceOnUse")
@@ -82,8 +97,8 @@ impl Layer for BackgroundDiagonalSplitPattern {
                 let color4 = base_color.unwrap().derive_similar_color(random);
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
-                    gradient_definition(random, Some(45), color3, color4),
+                    gradient_definition(random, ids, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color3, color4),
                 )
             } else {
                 // Pick a random color
@@ -97,8 +112,8 @@ impl Layer for BackgroundDiagonalSplitPattern {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                 )
             };
 
@@ -121,13 +136,12 @@ impl Layer for BackgroundDiagonalSplitPattern {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            small_elements::small_element_diagonal_split_square::SmallElementDiagonalSplitSquare
-                .type_id(),
-            small_elements::small_element_triangle::SmallElementTriangle.type_id(),
-            small_elements::small_element_square::SmallElementSquare.type_id(),
-            small_elements::small_element_square_shadow::SmallElementSquareShadow.type_id(),
+            Exclusion::Layer(small_elements::small_element_diagonal_split_square::SmallElementDiagonalSplitSquare.id()),
+            Exclusion::Layer(small_elements::small_element_triangle::SmallElementTriangle.id()),
+            Exclusion::Layer(small_elements::small_element_square::SmallElementSquare.id()),
+            Exclusion::Layer(small_elements::small_element_square_shadow::SmallElementSquareShadow.id()),
         ]
     }
 }
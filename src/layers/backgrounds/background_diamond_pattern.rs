@@ -1,15 +1,35 @@
-use std::any::Any;
-
-use crate::layers::Layer;
+use crate::layers::{Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
 use crate::{hsl::*, layers::small_elements};
-use random::Random;
 use svg::node::element::{Definitions, Element, Pattern, Polygon, Rectangle};
 
 pub struct BackgroundDiagmondPattern;
 
 impl Layer for BackgroundDiagmondPattern {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundDiagmondPattern"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        5
+    }
+
+    // The id has a typo, but it's stored on-ledger so only the display name is fixed
+    fn display_name(&self) -> String {
+        "Diamond Pattern".to_string()
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the rectangle that will be our background
         let mut pattern_rectangle = Rectangle::new().set("width", "100%").set("height", "100%");
 
@@ -17,7 +37,7 @@ impl Layer for BackgroundDiagmondPattern {
         let mut polygon = Polygon::new().set("points", "0,125 125,0 250,125 125,250");
 
         // Generate the pattern
-        let pattern_name = format!("pat{}", random.in_range::<u16>(0, 65535));
+        let pattern_name = ids.allocate(random, DefinitionKind::Pattern);
         let mut pattern = Pattern::new()
             .set("id", pattern_name.clone())
             .set("patternUnits", "userSpaceOnUse")
@@ -72,7 +92,7 @@ impl Layer for BackgroundDiagmondPattern {
                 let color3 = base_color.unwrap().derive_similar_color(random).as_string();
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color1, color2),
                     color3,
                 )
             } else {
@@ -87,7 +107,7 @@ impl Layer for BackgroundDiagmondPattern {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                     HSL::new_random(random, color_mode, 100).as_string(),
                 )
             };
@@ -108,14 +128,13 @@ impl Layer for BackgroundDiagmondPattern {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            small_elements::small_element_diagonal_split_square::SmallElementDiagonalSplitSquare
-                .type_id(),
-            small_elements::small_element_triangle::SmallElementTriangle.type_id(),
-            small_elements::small_element_square::SmallElementSquare.type_id(),
-            small_elements::small_element_square_shadow::SmallElementSquareShadow.type_id(),
-            small_elements::small_element_double_diamond::SmallElementDoubleDiamond.type_id(),
+            Exclusion::Layer(small_elements::small_element_diagonal_split_square::SmallElementDiagonalSplitSquare.id()),
+            Exclusion::Layer(small_elements::small_element_triangle::SmallElementTriangle.id()),
+            Exclusion::Layer(small_elements::small_element_square::SmallElementSquare.id()),
+            Exclusion::Layer(small_elements::small_element_square_shadow::SmallElementSquareShadow.id()),
+            Exclusion::Layer(small_elements::small_element_double_diamond::SmallElementDoubleDiamond.id()),
         ]
     }
 }
@@ -1,16 +1,33 @@
-use std::any::Any;
-
 use crate::hsl::*;
 use crate::layers::{big_elements, overlays};
-use crate::{layers::Layer, utils::*};
-use random::Random;
-use svg::node::element::path::Data;
+use crate::rng::Random;
+use crate::{
+    layers::{Exclusion, Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Path};
 
 pub struct BackgroundDoubleDiagonalSplit;
 
 impl Layer for BackgroundDoubleDiagonalSplit {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundDoubleDiagonalSplit"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        15
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the areas
         let area1 = Data::new()
             .move_to((0, 0))
@@ -105,10 +122,10 @@ impl Layer for BackgroundDoubleDiagonalSplit {
                 let color8 = base_color.unwrap().derive_similar_color(random);
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
-                    gradient_definition(random, Some(45), color3, color4),
-                    gradient_definition(random, Some(45), color5, color6),
-                    gradient_definition(random, Some(45), color7, color8),
+                    gradient_definition(random, ids, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color3, color4),
+                    gradient_definition(random, ids, Some(45), color5, color6),
+                    gradient_definition(random, ids, Some(45), color7, color8),
                 )
             } else {
                 // Pick a random color
@@ -122,10 +139,10 @@ impl Layer for BackgroundDoubleDiagonalSplit {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                 )
             };
 
@@ -148,13 +165,15 @@ impl Layer for BackgroundDoubleDiagonalSplit {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            big_elements::big_element_zig_zag::BigElementZigZag.type_id(),
-            big_elements::big_element_pill::BigElementPill.type_id(),
-            big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.type_id(),
-            big_elements::big_element_pill_ball::BigElementPillBall.type_id(),
-            overlays::overlay_triangle::OverlayTriangle.type_id(),
+            Exclusion::Layer(big_elements::big_element_zig_zag::BigElementZigZag.id()),
+            Exclusion::Layer(big_elements::big_element_pill::BigElementPill.id()),
+            Exclusion::Layer(
+                big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.id(),
+            ),
+            Exclusion::Layer(big_elements::big_element_pill_ball::BigElementPillBall.id()),
+            Exclusion::Layer(overlays::overlay_triangle::OverlayTriangle.id()),
         ]
     }
 }
@@ -1,15 +1,31 @@
-use std::any::Any;
-
 use crate::hsl::*;
 use crate::layers::big_elements;
-use crate::layers::Layer;
-use random::Random;
+use crate::layers::{Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
+use crate::utils::IdAllocator;
 use svg::node::element::{Element, Rectangle};
 
 pub struct BackgroundFourSquares;
 
 impl Layer for BackgroundFourSquares {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundFourSquares"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        _ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the squares that will make up the four squares background
         let mut rectangle1 = Rectangle::new() // Top-left
             .set("x", 0)
@@ -77,9 +93,9 @@ impl Layer for BackgroundFourSquares {
         ]
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            big_elements::big_element_two_squares::BigElementTwoSquares.type_id(), // The two squares big element doesn't differentiate from this background
+            Exclusion::Layer(big_elements::big_element_two_squares::BigElementTwoSquares.id()), // The two squares big element doesn't differentiate from this background
         ]
     }
 }
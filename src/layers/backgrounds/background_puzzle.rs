@@ -1,15 +1,33 @@
-use std::any::Any;
-
 use crate::hsl::*;
 use crate::layers::{big_elements, small_elements};
-use crate::{layers::Layer, utils::*};
-use random::Random;
+use crate::rng::Random;
+use crate::{
+    layers::{Exclusion, Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Polygon};
 
 pub struct BackgroundPuzzle;
 
 impl Layer for BackgroundPuzzle {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundPuzzle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the two pieces that will form the background
         let mut piece1 = Polygon::new().set("points", "0,0 666,0 666,500 334,500 334,1000 0,1000");
 
@@ -67,8 +85,8 @@ impl Layer for BackgroundPuzzle {
                 let color4 = base_color.unwrap().derive_similar_color(random);
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
-                    gradient_definition(random, Some(45), color3, color4),
+                    gradient_definition(random, ids, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color3, color4),
                 )
             } else {
                 // Pick a random color
@@ -82,8 +100,8 @@ impl Layer for BackgroundPuzzle {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                 )
             };
 
@@ -100,19 +118,29 @@ impl Layer for BackgroundPuzzle {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            big_elements::big_element_quarter_circle::BigElementQuarterCircle.type_id(),
-            big_elements::big_element_two_squares::BigElementTwoSquares.type_id(),
-            big_elements::big_element_triangle::BigElementTriangle.type_id(),
-            big_elements::big_element_pill::BigElementPill.type_id(),
-            big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.type_id(),
-            big_elements::big_element_pill_ball::BigElementPillBall.type_id(),
-            big_elements::big_element_three_quarter_circle::BigElementThreeQuarterCircle.type_id(),
-            big_elements::big_element_half_circle::BigElementHalfCircle.type_id(),
-            big_elements::big_element_two_rectangles::BigElementTwoRectangles.type_id(),
-            small_elements::small_element_cross::SmallElementCross.type_id(),
-            small_elements::small_element_cross_shadow::SmallElementCrossShadow.type_id(),
+            Exclusion::Layer(
+                big_elements::big_element_quarter_circle::BigElementQuarterCircle.id(),
+            ),
+            Exclusion::Layer(big_elements::big_element_two_squares::BigElementTwoSquares.id()),
+            Exclusion::Layer(big_elements::big_element_triangle::BigElementTriangle.id()),
+            Exclusion::Layer(big_elements::big_element_pill::BigElementPill.id()),
+            Exclusion::Layer(
+                big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.id(),
+            ),
+            Exclusion::Layer(big_elements::big_element_pill_ball::BigElementPillBall.id()),
+            Exclusion::Layer(
+                big_elements::big_element_three_quarter_circle::BigElementThreeQuarterCircle.id(),
+            ),
+            Exclusion::Layer(big_elements::big_element_half_circle::BigElementHalfCircle.id()),
+            Exclusion::Layer(
+                big_elements::big_element_two_rectangles::BigElementTwoRectangles.id(),
+            ),
+            Exclusion::Layer(small_elements::small_element_cross::SmallElementCross.id()),
+            Exclusion::Layer(
+                small_elements::small_element_cross_shadow::SmallElementCrossShadow.id(),
+            ),
         ]
     }
 }
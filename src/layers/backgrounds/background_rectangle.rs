@@ -1,13 +1,30 @@
 use crate::hsl::*;
-use crate::layers::Layer;
+use crate::layers::{Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
-use random::Random;
 use svg::node::element::{Element, Rectangle};
 
 pub struct BackgroundRectangle;
 
 impl Layer for BackgroundRectangle {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundRectangle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the rectangle that will be our background
         let mut rectangle = Rectangle::new().set("width", "100%").set("height", "100%");
 
@@ -40,7 +57,7 @@ impl Layer for BackgroundRectangle {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -52,7 +69,7 @@ impl Layer for BackgroundRectangle {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             rectangle = rectangle.set("fill", format!("url(#{gradient_name})",));
@@ -1,15 +1,33 @@
-use std::any::Any;
-
 use crate::hsl::*;
 use crate::layers::big_elements;
-use crate::{layers::Layer, utils::*};
-use random::Random;
+use crate::rng::Random;
+use crate::{
+    layers::{Exclusion, Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Rectangle};
 
 pub struct BackgroundStraightSplit;
 
 impl Layer for BackgroundStraightSplit {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundStraightSplit"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the two rectangles that will make up the straight split background
         let mut rectangle1 = Rectangle::new()
             .set("x", 0)
@@ -71,8 +89,8 @@ impl Layer for BackgroundStraightSplit {
                 let color4 = base_color.unwrap().derive_similar_color(random);
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
-                    gradient_definition(random, Some(45), color3, color4),
+                    gradient_definition(random, ids, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color3, color4),
                 )
             } else {
                 // Pick a random color
@@ -86,8 +104,8 @@ impl Layer for BackgroundStraightSplit {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                 )
             };
 
@@ -104,9 +122,9 @@ impl Layer for BackgroundStraightSplit {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            big_elements::big_element_two_squares::BigElementTwoSquares.type_id(), // The two squares big element doesn't differentiate enough from this background
+            Exclusion::Layer(big_elements::big_element_two_squares::BigElementTwoSquares.id()), // The two squares big element doesn't differentiate enough from this background
         ]
     }
 }
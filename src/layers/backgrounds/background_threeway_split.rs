@@ -1,15 +1,33 @@
-use std::any::Any;
-
 use crate::hsl::*;
 use crate::layers::{big_elements, overlays};
-use crate::{layers::Layer, utils::*};
-use random::Random;
+use crate::rng::Random;
+use crate::{
+    layers::{Exclusion, Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Polygon};
 
 pub struct BackgroundThreeWaySplit;
 
 impl Layer for BackgroundThreeWaySplit {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundThreeWaySplit"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the areas
         let mut area1 = Polygon::new().set("points", "0,0 500,0 500,500, 0,800");
         let mut area2 = Polygon::new().set("points", "1000,0 500,0 500,500, 1000,800");
@@ -75,9 +93,9 @@ impl Layer for BackgroundThreeWaySplit {
                 let color6 = base_color.unwrap().derive_similar_color(random);
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
-                    gradient_definition(random, Some(45), color3, color4),
-                    gradient_definition(random, Some(45), color5, color6),
+                    gradient_definition(random, ids, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color3, color4),
+                    gradient_definition(random, ids, Some(45), color5, color6),
                 )
             } else {
                 // Pick a random color
@@ -91,9 +109,9 @@ impl Layer for BackgroundThreeWaySplit {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                 )
             };
 
@@ -113,18 +131,24 @@ impl Layer for BackgroundThreeWaySplit {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            big_elements::big_element_half_circle::BigElementHalfCircle.type_id(),
-            big_elements::big_element_triangle::BigElementTriangle.type_id(),
-            big_elements::big_element_two_squares::BigElementTwoSquares.type_id(),
-            big_elements::big_element_quarter_circle::BigElementQuarterCircle.type_id(),
-            big_elements::big_element_zig_zag::BigElementZigZag.type_id(),
-            big_elements::big_element_pill::BigElementPill.type_id(),
-            big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.type_id(),
-            big_elements::big_element_pill_ball::BigElementPillBall.type_id(),
-            big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.type_id(),
-            overlays::overlay_triangle::OverlayTriangle.type_id(),
+            Exclusion::Layer(big_elements::big_element_half_circle::BigElementHalfCircle.id()),
+            Exclusion::Layer(big_elements::big_element_triangle::BigElementTriangle.id()),
+            Exclusion::Layer(big_elements::big_element_two_squares::BigElementTwoSquares.id()),
+            Exclusion::Layer(
+                big_elements::big_element_quarter_circle::BigElementQuarterCircle.id(),
+            ),
+            Exclusion::Layer(big_elements::big_element_zig_zag::BigElementZigZag.id()),
+            Exclusion::Layer(big_elements::big_element_pill::BigElementPill.id()),
+            Exclusion::Layer(
+                big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.id(),
+            ),
+            Exclusion::Layer(big_elements::big_element_pill_ball::BigElementPillBall.id()),
+            Exclusion::Layer(
+                big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.id(),
+            ),
+            Exclusion::Layer(overlays::overlay_triangle::OverlayTriangle.id()),
         ]
     }
 }
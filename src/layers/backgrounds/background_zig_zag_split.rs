@@ -1,16 +1,33 @@
-use std::any::Any;
-
 use crate::hsl::*;
 use crate::layers::{big_elements, overlays, small_elements};
-use crate::{layers::Layer, utils::*};
-use random::Random;
-use svg::node::element::path::Data;
+use crate::rng::Random;
+use crate::{
+    layers::{Exclusion, Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Path, Rectangle};
 
 pub struct BackgroundZigZagSplit;
 
 impl Layer for BackgroundZigZagSplit {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BackgroundZigZagSplit"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Background
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the paths that will form our zig zag background
         let mut rectangle = Rectangle::new()
             .set("x", 450)
@@ -87,8 +104,8 @@ impl Layer for BackgroundZigZagSplit {
                 let color4 = base_color.unwrap().derive_similar_color(random);
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
-                    gradient_definition(random, Some(45), color3, color4),
+                    gradient_definition(random, ids, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color3, color4),
                 )
             } else {
                 // Pick a random color
@@ -102,8 +119,8 @@ impl Layer for BackgroundZigZagSplit {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                 )
             };
 
@@ -120,20 +137,19 @@ impl Layer for BackgroundZigZagSplit {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            big_elements::big_element_zig_zag::BigElementZigZag.type_id(),
-            big_elements::big_element_triangle::BigElementTriangle.type_id(),
-            big_elements::big_element_three_quarter_circle::BigElementThreeQuarterCircle.type_id(),
-            big_elements::big_element_half_circle::BigElementHalfCircle.type_id(),
-            big_elements::big_element_pill::BigElementPill.type_id(),
-            big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.type_id(),
-            big_elements::big_element_pill_ball::BigElementPillBall.type_id(),
-            big_elements::big_element_two_squares::BigElementTwoSquares.type_id(),
-            big_elements::big_element_two_rectangles::BigElementTwoRectangles.type_id(),
-            overlays::overlay_triangle::OverlayTriangle.type_id(),
-            small_elements::small_element_split_circle_opposite::SmallElementSplitCircleOpposite
-                .type_id(),
+            Exclusion::Layer(big_elements::big_element_zig_zag::BigElementZigZag.id()),
+            Exclusion::Layer(big_elements::big_element_triangle::BigElementTriangle.id()),
+            Exclusion::Layer(big_elements::big_element_three_quarter_circle::BigElementThreeQuarterCircle.id()),
+            Exclusion::Layer(big_elements::big_element_half_circle::BigElementHalfCircle.id()),
+            Exclusion::Layer(big_elements::big_element_pill::BigElementPill.id()),
+            Exclusion::Layer(big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.id()),
+            Exclusion::Layer(big_elements::big_element_pill_ball::BigElementPillBall.id()),
+            Exclusion::Layer(big_elements::big_element_two_squares::BigElementTwoSquares.id()),
+            Exclusion::Layer(big_elements::big_element_two_rectangles::BigElementTwoRectangles.id()),
+            Exclusion::Layer(overlays::overlay_triangle::OverlayTriangle.id()),
+            Exclusion::Layer(small_elements::small_element_split_circle_opposite::SmallElementSplitCircleOpposite.id()),
         ]
     }
 }
@@ -1,12 +1,36 @@
 use crate::hsl::*;
-use crate::{layers::Layer, utils::*};
-use random::Random;
+use crate::rng::Random;
+use crate::{
+    layers::{Layer, LayerCategory, TAG_COVERS_FRAME},
+    utils::*,
+};
 use svg::node::element::{Circle, Element};
 
 pub struct BigElementFullCircle;
 
 impl Layer for BigElementFullCircle {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BigElementFullCircle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::BigElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec![TAG_COVERS_FRAME]
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Pick a random radius to introduce some variety
         let random_radius = random.in_range::<u16>(125, 175) * 2; // Always an even number
 
@@ -46,7 +70,7 @@ impl Layer for BigElementFullCircle {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -56,7 +80,7 @@ impl Layer for BigElementFullCircle {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             circle = circle.set("fill", format!("url(#{gradient_name})",));
@@ -1,15 +1,34 @@
-use std::any::Any;
-
-use crate::layers::Layer;
+use crate::layers::{Exclusion, Layer, LayerCategory, TAG_COVERS_FRAME};
+use crate::rng::Random;
 use crate::utils::*;
 use crate::{hsl::*, layers::small_elements};
-use random::Random;
 use svg::node::element::{Element, Rectangle};
 
 pub struct BigElementPill;
 
 impl Layer for BigElementPill {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BigElementPill"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::BigElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        25
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec![TAG_COVERS_FRAME]
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Build the rectangle
         let mut rectangle = Rectangle::new()
             .set("width", 500)
@@ -55,7 +74,7 @@ impl Layer for BigElementPill {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -65,7 +84,7 @@ impl Layer for BigElementPill {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             rectangle = rectangle.set("fill", format!("url(#{gradient_name})",));
@@ -74,7 +93,9 @@ impl Layer for BigElementPill {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
-        vec![small_elements::small_element_cube::SmallElementCube.type_id()]
+    fn exclusions(&self) -> Vec<Exclusion> {
+        vec![Exclusion::Layer(
+            small_elements::small_element_cube::SmallElementCube.id(),
+        )]
     }
 }
@@ -1,15 +1,36 @@
-use std::any::Any;
-
-use crate::layers::{overlays, Layer};
+use crate::hsl::*;
+use crate::layers::{overlays, Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
-use crate::{hsl::*, layers::small_elements};
-use random::Random;
 use svg::node::element::{Circle, Definitions, Element, RadialGradient, Rectangle, Stop};
 
 pub struct BigElementPillBall;
 
 impl Layer for BigElementPillBall {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BigElementPillBall"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::BigElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        25
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        // Unlike the other big elements, this one was never excluded by frames. It doesn't get the CoversFrame tag,
+        // so existing seeds keep producing the same image.
+        vec![]
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Initalialize the elements vector
         let mut elements: Vec<Element> = vec![];
 
@@ -57,7 +78,7 @@ impl Layer for BigElementPillBall {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -67,7 +88,7 @@ impl Layer for BigElementPillBall {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             pill = pill.set("fill", format!("url(#{gradient_pill_name})"));
@@ -99,7 +120,7 @@ impl Layer for BigElementPillBall {
         };
 
         // Set up the radial gradient for the ball
-        let gradient_name = format!("gr{}", random.in_range::<u16>(0, 65535));
+        let gradient_name = ids.allocate(random, DefinitionKind::Gradient);
         let gradient_color1 = HSL {
             lightness: 95,
             ..color
@@ -138,41 +159,12 @@ impl Layer for BigElementPillBall {
         elements
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
             // Small elements don't combine well with this element
-            small_elements::small_element_arch::SmallElementArch.type_id(),
-            small_elements::small_element_arch_shadow::SmallElementArchShadow.type_id(),
-            small_elements::small_element_cross::SmallElementCross.type_id(),
-            small_elements::small_element_cube::SmallElementCube.type_id(),
-            small_elements::small_element_flower::SmallElementFlower.type_id(),
-            small_elements::small_element_flower_shadow::SmallElementFlowerShadow.type_id(),
-            small_elements::small_element_four_circles::SmallElementFourCircles.type_id(),
-            small_elements::small_element_circle::SmallElementCircle.type_id(),
-            small_elements::small_element_square::SmallElementSquare.type_id(),
-            small_elements::small_element_split_circle_opposite::SmallElementSplitCircleOpposite
-                .type_id(),
-            small_elements::small_element_split_circle::SmallElementSplitCircle.type_id(),
-            small_elements::small_element_star::SmallElementStar.type_id(),
-            small_elements::small_element_star_shadow::SmallElementStarShadow.type_id(),
-            small_elements::small_element_triangle::SmallElementTriangle.type_id(),
-            small_elements::small_element_pill::SmallElementPill.type_id(),
-            small_elements::small_element_pill_shadow::SmallElementPillShadow.type_id(),
-            small_elements::small_element_straight_split_square::SmallElementStraightSplitSquare
-                .type_id(),
-            small_elements::small_element_stacked_pills::SmallElementStackedPills.type_id(),
-            small_elements::small_element_diagonal_split_square::SmallElementDiagonalSplitSquare
-                .type_id(),
-            small_elements::small_element_octagon::SmallElementOctagon.type_id(),
-            small_elements::small_element_octagon_shadow::SmallElementOctagonShadow.type_id(),
-            small_elements::small_element_ball::SmallElementBall.type_id(),
-            small_elements::small_element_double_circle::SmallElementDoubleCircle.type_id(),
-            small_elements::small_element_double_diamond::SmallElementDoubleDiamond.type_id(),
-            small_elements::small_element_cross_shadow::SmallElementCrossShadow.type_id(),
-            small_elements::small_element_circle_shadow::SmallElementCircleShadow.type_id(),
-            small_elements::small_element_square_shadow::SmallElementSquareShadow.type_id(),
-            overlays::overlay_triangle::OverlayTriangle.type_id(),
-            overlays::overlay_half_circle::OverlayHalfCircle.type_id(),
+            Exclusion::Category(LayerCategory::SmallElement),
+            Exclusion::Layer(overlays::overlay_triangle::OverlayTriangle.id()),
+            Exclusion::Layer(overlays::overlay_half_circle::OverlayHalfCircle.id()),
         ]
     }
 }
@@ -1,15 +1,34 @@
-use std::any::Any;
-
-use crate::layers::{overlays, Layer};
+use crate::hsl::*;
+use crate::layers::{overlays, Exclusion, Layer, LayerCategory, TAG_COVERS_FRAME};
+use crate::rng::Random;
 use crate::utils::*;
-use crate::{hsl::*, layers::small_elements};
-use random::Random;
-use svg::node::element::{path::Data, Element, Path, Rectangle};
+use svg::node::element::{Element, Path, Rectangle};
 
 pub struct BigElementPillSplitCircle;
 
 impl Layer for BigElementPillSplitCircle {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BigElementPillSplitCircle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::BigElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        25
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec![TAG_COVERS_FRAME]
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Build the rectangle
         let mut pill = Rectangle::new()
             .set("width", 500)
@@ -109,8 +128,8 @@ impl Layer for BigElementPillSplitCircle {
                 let color4 = base_color.unwrap().derive_similar_color(random);
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
-                    gradient_definition(random, Some(45), color3, color4),
+                    gradient_definition(random, ids, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color3, color4),
                 )
             } else {
                 // Pick a random color
@@ -122,8 +141,8 @@ impl Layer for BigElementPillSplitCircle {
                 };
 
                 (
-                    random_gradient_definition(random, Some(45), color_mode, 100),
-                    random_gradient_definition(random, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
+                    random_gradient_definition(random, ids, Some(45), color_mode, 100),
                 )
             };
 
@@ -139,41 +158,12 @@ impl Layer for BigElementPillSplitCircle {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
             // Small elements don't combine well with this element
-            small_elements::small_element_arch::SmallElementArch.type_id(),
-            small_elements::small_element_arch_shadow::SmallElementArchShadow.type_id(),
-            small_elements::small_element_cross::SmallElementCross.type_id(),
-            small_elements::small_element_cube::SmallElementCube.type_id(),
-            small_elements::small_element_flower::SmallElementFlower.type_id(),
-            small_elements::small_element_flower_shadow::SmallElementFlowerShadow.type_id(),
-            small_elements::small_element_four_circles::SmallElementFourCircles.type_id(),
-            small_elements::small_element_circle::SmallElementCircle.type_id(),
-            small_elements::small_element_square::SmallElementSquare.type_id(),
-            small_elements::small_element_split_circle_opposite::SmallElementSplitCircleOpposite
-                .type_id(),
-            small_elements::small_element_split_circle::SmallElementSplitCircle.type_id(),
-            small_elements::small_element_star::SmallElementStar.type_id(),
-            small_elements::small_element_star_shadow::SmallElementStarShadow.type_id(),
-            small_elements::small_element_triangle::SmallElementTriangle.type_id(),
-            small_elements::small_element_pill::SmallElementPill.type_id(),
-            small_elements::small_element_pill_shadow::SmallElementPillShadow.type_id(),
-            small_elements::small_element_straight_split_square::SmallElementStraightSplitSquare
-                .type_id(),
-            small_elements::small_element_stacked_pills::SmallElementStackedPills.type_id(),
-            small_elements::small_element_diagonal_split_square::SmallElementDiagonalSplitSquare
-                .type_id(),
-            small_elements::small_element_octagon::SmallElementOctagon.type_id(),
-            small_elements::small_element_octagon_shadow::SmallElementOctagonShadow.type_id(),
-            small_elements::small_element_ball::SmallElementBall.type_id(),
-            small_elements::small_element_double_circle::SmallElementDoubleCircle.type_id(),
-            small_elements::small_element_double_diamond::SmallElementDoubleDiamond.type_id(),
-            small_elements::small_element_cross_shadow::SmallElementCrossShadow.type_id(),
-            small_elements::small_element_circle_shadow::SmallElementCircleShadow.type_id(),
-            small_elements::small_element_square_shadow::SmallElementSquareShadow.type_id(),
-            overlays::overlay_triangle::OverlayTriangle.type_id(),
-            overlays::overlay_half_circle::OverlayHalfCircle.type_id(),
+            Exclusion::Category(LayerCategory::SmallElement),
+            Exclusion::Layer(overlays::overlay_triangle::OverlayTriangle.id()),
+            Exclusion::Layer(overlays::overlay_half_circle::OverlayHalfCircle.id()),
         ]
     }
 }
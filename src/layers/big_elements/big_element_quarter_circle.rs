@@ -1,12 +1,36 @@
 use crate::hsl::*;
-use crate::{layers::Layer, utils::*};
-use random::Random;
-use svg::node::element::{path::Data, Element, Path};
+use crate::rng::Random;
+use crate::{
+    layers::{Layer, LayerCategory, TAG_COVERS_FRAME},
+    utils::*,
+};
+use svg::node::element::{Element, Path};
 
 pub struct BigElementQuarterCircle;
 
 impl Layer for BigElementQuarterCircle {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BigElementQuarterCircle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::BigElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec![TAG_COVERS_FRAME]
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Pick a position
         let data = match random.roll::<u8>(4) {
             0 => {
@@ -74,7 +98,7 @@ impl Layer for BigElementQuarterCircle {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -84,7 +108,7 @@ impl Layer for BigElementQuarterCircle {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             path = path.set("fill", format!("url(#{gradient_name})",));
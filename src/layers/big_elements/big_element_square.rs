@@ -1,13 +1,34 @@
 use crate::hsl::*;
-use crate::layers::Layer;
+use crate::layers::{Layer, LayerCategory, TAG_COVERS_FRAME};
+use crate::rng::Random;
 use crate::utils::*;
-use random::Random;
 use svg::node::element::{Element, Rectangle};
 
 pub struct BigElementSquare;
 
 impl Layer for BigElementSquare {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BigElementSquare"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::BigElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec![TAG_COVERS_FRAME]
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the required values for building the rectangle. It will vary in size
         // and we have to adjust its position with it.
         let random_dimension = random.in_range::<u16>(200, 250) * 2;
@@ -62,7 +83,7 @@ impl Layer for BigElementSquare {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -72,7 +93,7 @@ impl Layer for BigElementSquare {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             rectangle = rectangle.set("fill", format!("url(#{gradient_name})",));
@@ -1,15 +1,37 @@
-use std::any::Any;
-
 use crate::hsl::*;
 use crate::layers::overlays;
-use crate::{layers::Layer, utils::*};
-use random::Random;
-use svg::node::element::{path::Data, Element, Path};
+use crate::rng::Random;
+use crate::{
+    layers::{Exclusion, Layer, LayerCategory, TAG_COVERS_FRAME},
+    utils::*,
+};
+use svg::node::element::{Element, Path};
 
 pub struct BigElementThreeQuarterCircle;
 
 impl Layer for BigElementThreeQuarterCircle {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BigElementThreeQuarterCircle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::BigElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec![TAG_COVERS_FRAME]
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Randomly pick a direction
         let data = match random.roll::<u8>(4) {
             0 => {
@@ -76,7 +98,7 @@ impl Layer for BigElementThreeQuarterCircle {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -86,7 +108,7 @@ impl Layer for BigElementThreeQuarterCircle {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             path = path.set("fill", format!("url(#{gradient_name})",));
@@ -95,7 +117,9 @@ impl Layer for BigElementThreeQuarterCircle {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
-        vec![overlays::overlay_half_circle::OverlayHalfCircle.type_id()]
+    fn exclusions(&self) -> Vec<Exclusion> {
+        vec![Exclusion::Layer(
+            overlays::overlay_half_circle::OverlayHalfCircle.id(),
+        )]
     }
 }
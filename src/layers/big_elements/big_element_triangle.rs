@@ -1,12 +1,36 @@
 use crate::hsl::*;
-use crate::{layers::Layer, utils::*};
-use random::Random;
+use crate::rng::Random;
+use crate::{
+    layers::{Layer, LayerCategory, TAG_COVERS_FRAME},
+    utils::*,
+};
 use svg::node::element::{Element, Polygon};
 
 pub struct BigElementTriangle;
 
 impl Layer for BigElementTriangle {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BigElementTriangle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::BigElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec![TAG_COVERS_FRAME]
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate a triangle with a random positioning and appropriate gradient rotation
         let mut triangle = match random.roll::<u8>(8) {
             0 => Polygon::new().set("points", "0,0 500,500 0,1000"), // Base to left side
@@ -48,7 +72,7 @@ impl Layer for BigElementTriangle {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -58,7 +82,7 @@ impl Layer for BigElementTriangle {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             // Add the fill to the triangle
@@ -1,16 +1,34 @@
-use std::any::Any;
-
-use crate::layers::{overlays, Layer};
+use crate::layers::{overlays, Exclusion, Layer, LayerCategory, TAG_COVERS_FRAME};
+use crate::rng::Random;
 use crate::utils::*;
 use crate::{hsl::*, layers::frames};
-use random::Random;
-use svg::node::element::path::Data;
 use svg::node::element::{Element, Path};
 
 pub struct BigElementZigZag;
 
 impl Layer for BigElementZigZag {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "BigElementZigZag"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::BigElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        50
+    }
+
+    fn tags(&self) -> Vec<&'static str> {
+        vec![TAG_COVERS_FRAME]
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Randomly pick a rotation
         let valid_rotate_amounts = [0, 90, 180, 270];
         let rotate_amount = valid_rotate_amounts
@@ -72,7 +90,7 @@ impl Layer for BigElementZigZag {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(*gradient_rotate_amount), color1, color2)
+                gradient_definition(random, ids, Some(*gradient_rotate_amount), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -82,7 +100,13 @@ impl Layer for BigElementZigZag {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(*gradient_rotate_amount), color_mode, 100)
+                random_gradient_definition(
+                    random,
+                    ids,
+                    Some(*gradient_rotate_amount),
+                    color_mode,
+                    100,
+                )
             };
 
             path = path.set("fill", format!("url(#{gradient_name})",));
@@ -91,10 +115,10 @@ impl Layer for BigElementZigZag {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![
-            frames::frame_straight::FrameStraight.type_id(),
-            overlays::overlay_triangle::OverlayTriangle.type_id(),
+            Exclusion::Layer(frames::frame_straight::FrameStraight.id()),
+            Exclusion::Layer(overlays::overlay_triangle::OverlayTriangle.id()),
         ]
     }
 }
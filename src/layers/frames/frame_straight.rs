@@ -1,15 +1,30 @@
-use std::any::Any;
-
-use crate::layers::Layer;
+use crate::hsl::*;
+use crate::layers::{Exclusion, Layer, LayerCategory, TAG_COVERS_FRAME};
+use crate::rng::Random;
 use crate::utils::*;
-use crate::{hsl::*, layers::big_elements};
-use random::Random;
 use svg::node::element::{Element, Rectangle};
 
 pub struct FrameStraight;
 
 impl Layer for FrameStraight {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "FrameStraight"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Frame
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Randomly set stroke width
         let valid_stroke_widths = [100]; // Should be divisable by 2
         let stroke_width = valid_stroke_widths
@@ -66,7 +81,7 @@ impl Layer for FrameStraight {
                     ..unwrapped
                 };
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -76,7 +91,7 @@ impl Layer for FrameStraight {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             rectangle = rectangle.set("stroke", format!("url(#{gradient_name})",));
@@ -85,20 +100,8 @@ impl Layer for FrameStraight {
         }
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         // Prevent the frame from having any big elements on top
-        vec![
-            big_elements::big_element_square::BigElementSquare.type_id(),
-            big_elements::big_element_full_circle::BigElementFullCircle.type_id(),
-            big_elements::big_element_half_circle::BigElementHalfCircle.type_id(),
-            big_elements::big_element_quarter_circle::BigElementQuarterCircle.type_id(),
-            big_elements::big_element_three_quarter_circle::BigElementThreeQuarterCircle.type_id(),
-            big_elements::big_element_triangle::BigElementTriangle.type_id(),
-            big_elements::big_element_two_squares::BigElementTwoSquares.type_id(),
-            big_elements::big_element_zig_zag::BigElementZigZag.type_id(),
-            big_elements::big_element_pill::BigElementPill.type_id(),
-            big_elements::big_element_pill_split_circle::BigElementPillSplitCircle.type_id(),
-            big_elements::big_element_two_rectangles::BigElementTwoRectangles.type_id(),
-        ]
+        vec![Exclusion::Tag(TAG_COVERS_FRAME)]
     }
 }
@@ -1,21 +1,16 @@
-use std::any::TypeId;
+use crate::layers::{random_layer, Exclusion, Layer, LayerCategory, LayerWeights};
+use crate::rng::Random;
 
-use crate::{
-    layers::Layer,
-    utils::{exclude_layers, pick_random_layer},
-};
-use frame_straight::FrameStraight;
-use random::Random;
-
-pub mod frame_straight;
-
-pub fn random_frame(random: &mut Random, exclusions: &[TypeId]) -> Option<Box<dyn Layer>> {
-    // Layers and their weights
-    let available_layers: Vec<(Box<dyn Layer>, u32)> = vec![(Box::new(FrameStraight), 100)];
-
-    // Filter out the excluded layers
-    let allowed_layers = exclude_layers(available_layers, exclusions);
+// The order of this list is part of the generator output, so new layers go at the end
+register_layers! {
+    frame_layers:
+    frame_straight::FrameStraight,
+}
 
-    // Pick a random layer based on the weights of the allowed layers
-    pick_random_layer(random, allowed_layers)
+pub fn random_frame(
+    random: &mut Random,
+    exclusions: &[Exclusion],
+    weights: &dyn LayerWeights,
+) -> Option<Box<dyn Layer>> {
+    random_layer(random, LayerCategory::Frame, exclusions, weights)
 }
@@ -1,20 +1,107 @@
 use crate::hsl::*;
-use random::Random;
-use std::any::{type_name, Any, TypeId};
+use crate::rng::Random;
+use crate::utils::IdAllocator;
 use svg::node::element::Element;
 
-pub trait Layer: Any {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element>;
+/// The category a layer belongs to, which determines where it ends up in the stack of layers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayerCategory {
+    Background,
+    Frame,
+    BigElement,
+    Overlay,
+    SmallElement,
+}
+
+impl LayerCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LayerCategory::Background => "Background",
+            LayerCategory::Frame => "Frame",
+            LayerCategory::BigElement => "BigElement",
+            LayerCategory::Overlay => "Overlay",
+            LayerCategory::SmallElement => "SmallElement",
+        }
+    }
+
+    /// A human-readable name for the category, like "Small Element"
+    pub fn display_name(&self) -> String {
+        split_words(self.as_str())
+    }
+}
+
+/// Tag for big elements that would cover a frame
+pub const TAG_COVERS_FRAME: &str = "CoversFrame";
+
+/// Describes which layers can't be combined with a layer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Exclusion {
+    /// A single layer, by id
+    Layer(&'static str),
+    /// All layers in a category
+    Category(LayerCategory),
+    /// All layers with a tag
+    Tag(&'static str),
+}
+
+impl Exclusion {
+    /// Returns whether a layer is excluded by this exclusion
+    pub fn excludes(&self, layer: &dyn Layer) -> bool {
+        match self {
+            Exclusion::Layer(id) => layer.id() == *id,
+            Exclusion::Category(category) => layer.category() == *category,
+            Exclusion::Tag(tag) => layer.tags().contains(tag),
+        }
+    }
+}
 
-    fn exclusions(&self) -> Vec<TypeId> {
+pub trait Layer {
+    /// A stable identifier for this layer, which is stored on-ledger. Never change it once the layer was used for a mint.
+    fn id(&self) -> &'static str;
+
+    fn category(&self) -> LayerCategory;
+
+    /// The weight that is used to pick this layer within its category, unless it's changed by an admin
+    fn default_weight(&self) -> u32;
+
+    /// Tags that other layers can use to exclude a group of layers at once
+    fn tags(&self) -> Vec<&'static str> {
         vec![]
     }
 
-    fn layer_type(&self) -> TypeId {
-        TypeId::of::<Self>()
+    /// Generates the SVG elements of this layer. Ids for definitions like gradients must come from `ids`.
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element>;
+
+    /// Returns the layers that can't be combined with this layer
+    fn exclusions(&self) -> Vec<Exclusion> {
+        vec![]
     }
 
     fn layer_name(&self) -> String {
-        type_name::<Self>().split("::").last().unwrap().to_string()
+        self.id().to_string()
     }
+
+    /// A human-readable name for the layer within its category, like "Star Shadow"
+    fn display_name(&self) -> String {
+        let id = self.id();
+        split_words(id.strip_prefix(self.category().as_str()).unwrap_or(id))
+    }
+}
+
+/// Splits a name in CamelCase into words
+fn split_words(name: &str) -> String {
+    let mut words = String::new();
+    for character in name.chars() {
+        if character.is_uppercase() && !words.is_empty() {
+            words.push(' ');
+        }
+        words.push(character);
+    }
+
+    words
 }
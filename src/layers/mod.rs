@@ -1,13 +1,68 @@
+/// Declares the layer modules of a category and builds the list of its layers with their default weights
+macro_rules! register_layers {
+    ($list_fn:ident: $($module:ident::$layer:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Returns all layers in this category with their default weights
+        pub fn $list_fn() -> Vec<(Box<dyn Layer>, u32)> {
+            vec![$(
+                (Box::new($module::$layer) as Box<dyn Layer>, $module::$layer.default_weight()),
+            )*]
+        }
+    };
+}
+
 pub mod backgrounds;
 pub mod big_elements;
 pub mod frames;
 pub mod layer;
 pub mod overlays;
 pub mod small_elements;
+pub mod weights;
 
 pub use backgrounds::*;
 pub use big_elements::*;
 pub use frames::*;
-pub use layer::Layer;
+pub use layer::{Exclusion, Layer, LayerCategory, TAG_COVERS_FRAME};
 pub use overlays::*;
 pub use small_elements::*;
+pub use weights::*;
+
+use crate::rng::Random;
+use crate::utils::{apply_weights, exclude_layers, pick_random_layer};
+
+/// Returns all layers of all categories with their default weights
+pub fn all_layers() -> Vec<(Box<dyn Layer>, u32)> {
+    let mut all_layers = background_layers();
+    all_layers.append(&mut frame_layers());
+    all_layers.append(&mut big_element_layers());
+    all_layers.append(&mut overlay_layers());
+    all_layers.append(&mut small_element_layers());
+
+    all_layers
+}
+
+/// Picks a random layer from a category, based on the weights of the layers that aren't excluded
+///
+/// Returns None if no layer could be picked
+pub fn random_layer(
+    random: &mut Random,
+    category: LayerCategory,
+    exclusions: &[Exclusion],
+    weights: &dyn LayerWeights,
+) -> Option<Box<dyn Layer>> {
+    // Layers in this category and their weights
+    let available_layers = apply_weights(
+        all_layers()
+            .into_iter()
+            .filter(|(layer, _)| layer.category() == category)
+            .collect(),
+        weights,
+    );
+
+    // Filter out the excluded layers
+    let allowed_layers = exclude_layers(available_layers, exclusions);
+
+    // Pick a random layer based on the weights of the allowed layers
+    pick_random_layer(random, allowed_layers)
+}
@@ -1,13 +1,30 @@
 use crate::hsl::*;
-use crate::layers::Layer;
+use crate::layers::{Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
-use random::Random;
 use svg::node::element::{Element, Polygon};
 
 pub struct OverlayDiamond;
 
 impl Layer for OverlayDiamond {
-    fn generate(&self, random: &mut Random, _base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "OverlayDiamond"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Overlay
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        _base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the areas
         let mut diamond = Polygon::new().set("points", "0,500 500,0 1000,500 500,1000");
 
@@ -51,7 +68,8 @@ impl Layer for OverlayDiamond {
         };
 
         // Set the gradient
-        let (gradient, gradient_name) = radial_gradient_definition(random, None, color1, color2);
+        let (gradient, gradient_name) =
+            radial_gradient_definition(random, ids, None, color1, color2);
 
         diamond = diamond.set("fill", format!("url(#{gradient_name})"));
 
@@ -59,7 +77,7 @@ impl Layer for OverlayDiamond {
         vec![gradient.into(), diamond.into()]
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![]
     }
 }
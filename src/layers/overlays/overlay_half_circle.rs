@@ -1,13 +1,30 @@
 use crate::hsl::*;
-use crate::layers::Layer;
+use crate::layers::{Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
-use random::Random;
-use svg::node::element::{path::Data, Element, Path};
+use svg::node::element::{Element, Path};
 
 pub struct OverlayHalfCircle;
 
 impl Layer for OverlayHalfCircle {
-    fn generate(&self, random: &mut Random, _base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "OverlayHalfCircle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Overlay
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        _base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the half circle
         let data = Data::new()
             .move_to((0, 0))
@@ -29,6 +46,7 @@ impl Layer for OverlayHalfCircle {
         // Set the gradient
         let (gradient, gradient_name) = gradient_definition(
             random,
+            ids,
             None,
             HSL {
                 // Opacity = 0
@@ -55,7 +73,7 @@ impl Layer for OverlayHalfCircle {
         elements
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![]
     }
 }
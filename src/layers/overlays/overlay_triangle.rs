@@ -1,13 +1,30 @@
 use crate::hsl::*;
-use crate::layers::Layer;
+use crate::layers::{Exclusion, Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
-use random::Random;
 use svg::node::element::{Element, Polygon};
 
 pub struct OverlayTriangle;
 
 impl Layer for OverlayTriangle {
-    fn generate(&self, random: &mut Random, _base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "OverlayTriangle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::Overlay
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        _base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the areas
         let mut triangle = Polygon::new().set("points", "0,0 0,1000 500,500");
 
@@ -62,7 +79,7 @@ impl Layer for OverlayTriangle {
         };
 
         // Set the gradient
-        let (gradient, gradient_name) = gradient_definition(random, None, color1, color2);
+        let (gradient, gradient_name) = gradient_definition(random, ids, None, color1, color2);
 
         triangle = triangle.set("fill", format!("url(#{gradient_name})"));
 
@@ -73,7 +90,7 @@ impl Layer for OverlayTriangle {
         elements
     }
 
-    fn exclusions(&self) -> Vec<std::any::TypeId> {
+    fn exclusions(&self) -> Vec<Exclusion> {
         vec![]
     }
 }
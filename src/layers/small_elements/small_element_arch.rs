@@ -1,13 +1,32 @@
 use crate::hsl::*;
-use crate::{layers::Layer, utils::*};
-use random::Random;
-use svg::node::element::path::Data;
+use crate::rng::Random;
+use crate::{
+    layers::{Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Path};
 
 pub struct SmallElementArch;
 
 impl Layer for SmallElementArch {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "SmallElementArch"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::SmallElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        50
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         let random_width = random.in_range::<u16>(50, 100);
         let radius = random_width / 2; // Will get rounded, but no big issue
 
@@ -51,7 +70,7 @@ impl Layer for SmallElementArch {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -61,7 +80,7 @@ impl Layer for SmallElementArch {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             path = path.set("fill", format!("url(#{gradient_name})",));
@@ -1,13 +1,32 @@
 use crate::hsl::*;
-use crate::{layers::Layer, utils::*};
-use random::Random;
-use svg::node::element::path::Data;
+use crate::rng::Random;
+use crate::{
+    layers::{Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Path};
 
 pub struct SmallElementArchShadow;
 
 impl Layer for SmallElementArchShadow {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "SmallElementArchShadow"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::SmallElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        50
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         let random_width = random.in_range::<u16>(50, 100);
         let radius = random_width / 2; // Will get rounded, but no big issue
 
@@ -69,7 +88,7 @@ impl Layer for SmallElementArchShadow {
                 .as_string();
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color1, color2),
                     color3,
                 )
             } else {
@@ -90,7 +109,7 @@ impl Layer for SmallElementArchShadow {
                 .as_string();
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color1, color2),
                     color3,
                 )
             };
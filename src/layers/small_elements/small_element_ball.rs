@@ -1,12 +1,30 @@
 use crate::hsl::*;
-use crate::layers::Layer;
-use random::Random;
+use crate::layers::{Layer, LayerCategory};
+use crate::rng::Random;
+use crate::utils::{DefinitionKind, IdAllocator};
 use svg::node::element::{Circle, Definitions, Element, RadialGradient, Stop};
 
 pub struct SmallElementBall;
 
 impl Layer for SmallElementBall {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "SmallElementBall"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::SmallElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         let random_radius = random.in_range::<u16>(35, 100) * 2; // Always an even number
 
         let mut circle = Circle::new()
@@ -31,7 +49,7 @@ impl Layer for SmallElementBall {
         };
 
         // Set up the radial gradient
-        let gradient_name = format!("gr{}", random.in_range::<u16>(0, 65535));
+        let gradient_name = ids.allocate(random, DefinitionKind::Gradient);
         let gradient_color1 = HSL {
             lightness: 95,
             ..color
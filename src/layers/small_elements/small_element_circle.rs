@@ -1,12 +1,32 @@
 use crate::hsl::*;
-use crate::{layers::Layer, utils::*};
-use random::Random;
+use crate::rng::Random;
+use crate::{
+    layers::{Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Circle, Element};
 
 pub struct SmallElementCircle;
 
 impl Layer for SmallElementCircle {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "SmallElementCircle"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::SmallElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        50
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         let random_radius = random.in_range::<u16>(35, 100) * 2; // Always an even number
 
         let mut circle = Circle::new()
@@ -44,7 +64,7 @@ impl Layer for SmallElementCircle {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Randomize the color mode, but prefer vibrant
                 let roll = random.roll::<u8>(100);
@@ -54,7 +74,7 @@ impl Layer for SmallElementCircle {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             circle = circle.set("fill", format!("url(#{gradient_name})",));
@@ -1,12 +1,32 @@
 use crate::hsl::*;
-use crate::{layers::Layer, utils::*};
-use random::Random;
+use crate::rng::Random;
+use crate::{
+    layers::{Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Circle, Element};
 
 pub struct SmallElementCircleShadow;
 
 impl Layer for SmallElementCircleShadow {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "SmallElementCircleShadow"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::SmallElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        50
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         let random_radius = random.in_range::<u16>(35, 100) * 2; // Always an even number
 
         let mut circle = Circle::new()
@@ -61,7 +81,7 @@ impl Layer for SmallElementCircleShadow {
                 .as_string();
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color1, color2),
                     color3,
                 )
             } else {
@@ -82,7 +102,7 @@ impl Layer for SmallElementCircleShadow {
                 .as_string();
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color1, color2),
                     color3,
                 )
             };
@@ -1,13 +1,32 @@
 use crate::hsl::*;
-use crate::{layers::Layer, utils::*};
-use random::Random;
-use svg::node::element::path::Data;
+use crate::rng::Random;
+use crate::{
+    layers::{Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Path};
 
 pub struct SmallElementCross;
 
 impl Layer for SmallElementCross {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "SmallElementCross"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::SmallElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        50
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Get a random width
         let random_size = random.in_range::<u16>(25, 50) * 6; // Must be divisible by 3 and 2
 
@@ -58,7 +77,7 @@ impl Layer for SmallElementCross {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Generate random gradients
                 let roll = random.roll::<u8>(100);
@@ -68,7 +87,7 @@ impl Layer for SmallElementCross {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             path = path.set("stroke", format!("url(#{gradient_name})"));
@@ -1,13 +1,32 @@
 use crate::hsl::*;
-use crate::{layers::Layer, utils::*};
-use random::Random;
-use svg::node::element::path::Data;
+use crate::rng::Random;
+use crate::{
+    layers::{Layer, LayerCategory},
+    utils::*,
+};
 use svg::node::element::{Element, Path};
 
 pub struct SmallElementCrossShadow;
 
 impl Layer for SmallElementCrossShadow {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "SmallElementCrossShadow"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::SmallElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        50
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Get a random width
         let random_size = random.in_range::<u16>(25, 50) * 6; // Must be divisible by 3 and 2
 
@@ -84,7 +103,7 @@ impl Layer for SmallElementCrossShadow {
                 .as_string();
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color1, color2),
                     color3,
                 )
             } else {
@@ -105,7 +124,7 @@ impl Layer for SmallElementCrossShadow {
                 .as_string();
 
                 (
-                    gradient_definition(random, Some(45), color1, color2),
+                    gradient_definition(random, ids, Some(45), color1, color2),
                     color3,
                 )
             };
@@ -1,13 +1,30 @@
 use crate::hsl::*;
-use crate::layers::Layer;
-use random::Random;
-use svg::node::element::path::Data;
+use crate::layers::{Layer, LayerCategory};
+use crate::rng::Random;
+use crate::utils::{Data, IdAllocator};
 use svg::node::element::{Element, Path, Rectangle};
 
 pub struct SmallElementCube;
 
 impl Layer for SmallElementCube {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "SmallElementCube"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::SmallElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        100
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        _ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         let random_size = random.in_range::<u16>(8, 21) * 12; // Must be divisible by 12 and 2
 
         let mut rectangle = Rectangle::new()
@@ -1,13 +1,30 @@
 use crate::hsl::*;
-use crate::layers::Layer;
+use crate::layers::{Layer, LayerCategory};
+use crate::rng::Random;
 use crate::utils::*;
-use random::Random;
 use svg::node::element::{Element, Rectangle};
 
 pub struct SmallElementPill;
 
 impl Layer for SmallElementPill {
-    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+    fn id(&self) -> &'static str {
+        "SmallElementPill"
+    }
+
+    fn category(&self) -> LayerCategory {
+        LayerCategory::SmallElement
+    }
+
+    fn default_weight(&self) -> u32 {
+        50
+    }
+
+    fn generate(
+        &self,
+        random: &mut Random,
+        base_color: &Option<HSL>,
+        ids: &mut IdAllocator,
+    ) -> Vec<Element> {
         // Generate the pill
         let random_width = random.in_range::<u16>(35, 75) * 4;
 
@@ -54,7 +71,7 @@ impl Layer for SmallElementPill {
                 let color1 = base_color.unwrap().derive_similar_color(random);
                 let color2 = base_color.unwrap().derive_similar_color(random);
 
-                gradient_definition(random, Some(45), color1, color2)
+                gradient_definition(random, ids, Some(45), color1, color2)
             } else {
                 // Pick a random color
                 let roll = random.roll::<u8>(100);
@@ -64,7 +81,7 @@ impl Layer for SmallElementPill {
                     ColorMode::Vibrant
                 };
 
-                random_gradient_definition(random, Some(45), color_mode, 100)
+                random_gradient_definition(random, ids, Some(45), color_mode, 100)
             };
 
             rectangle = rectangle.set("fill", format!("url(#{gradient_name})",));
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+/// The highest weight a layer can be given, so the total weight of all layers always fits in a u32 when picking one
+pub const MAX_LAYER_WEIGHT: u32 = 1_000_000;
+
+/// Provides the weights that are used to pick layers. A weight of 0 disables a layer.
+pub trait LayerWeights {
+    fn weight(&self, layer_name: &str, default_weight: u32) -> u32;
+}
+
+/// Uses the weights that are defined in the layer categories
+pub struct DefaultWeights;
+
+impl LayerWeights for DefaultWeights {
+    fn weight(&self, _layer_name: &str, default_weight: u32) -> u32 {
+        default_weight
+    }
+}
+
+/// Overrides the default weights of the layers in the map, keyed by layer name
+impl LayerWeights for HashMap<String, u32> {
+    fn weight(&self, layer_name: &str, default_weight: u32) -> u32 {
+        self.get(layer_name).copied().unwrap_or(default_weight)
+    }
+}
@@ -1,25 +1,80 @@
+#[cfg(feature = "blueprint")]
+use errors::{ensure, SVGenesisError};
+#[cfg(feature = "blueprint")]
 use events::Generation;
+#[cfg(feature = "blueprint")]
+use layers::{Layer, LayerCategory, LayerWeights, MAX_LAYER_WEIGHT};
+#[cfg(feature = "blueprint")]
 use scrypto::prelude::*;
-use types::SVGenesisNFT;
+#[cfg(feature = "blueprint")]
+use svg_data::SVGData;
+#[cfg(feature = "blueprint")]
+use types::{MintPhase, Rarity, SVGenesisNFT, SVGenesisTrait, StorageMode, TraitFrequency};
 
-pub mod events;
+// The generator, which doesn't depend on scrypto
+pub mod data_uri;
 pub mod hsl;
 pub mod layers;
 pub mod nft_generator;
-pub mod types;
+#[cfg(any(feature = "cli", feature = "wasm"))]
+pub mod preview;
+pub mod rng;
+pub mod svg_data;
 pub mod utils;
 
+// The blueprint, which wraps the generator
+#[cfg(feature = "blueprint")]
+pub mod errors;
+#[cfg(feature = "blueprint")]
+pub mod events;
+#[cfg(feature = "blueprint")]
+pub mod types;
+
+/// Layer weights that were changed by an admin, falling back to the default weights for all other layers
+#[cfg(feature = "blueprint")]
+struct StoredWeights<'a>(&'a KeyValueStore<String, u32>);
+
+#[cfg(feature = "blueprint")]
+impl LayerWeights for StoredWeights<'_> {
+    fn weight(&self, layer_name: &str, default_weight: u32) -> u32 {
+        self.0
+            .get(&layer_name.to_string())
+            .map(|weight| *weight)
+            .unwrap_or(default_weight)
+    }
+}
+
+#[cfg(feature = "blueprint")]
 #[blueprint]
-#[types(SVGenesisNFT, Vec<u8>, Hash, NonFungibleLocalId)]
+#[types(SVGenesisNFT, Vec<u8>, Hash, NonFungibleLocalId, u32, u64, String)]
 #[events(Generation)]
 mod svgenesis {
     enable_method_auth! {
         roles {
             admin_role => updatable_by: [OWNER];
+            random_provider => updatable_by: [OWNER];
         },
         methods {
             mint_nft => PUBLIC;
+            mint_nfts => PUBLIC;
+            request_mint => PUBLIC;
+            reroll => PUBLIC;
+            finish_mint => restrict_to: [random_provider];
+            abort_mint => restrict_to: [random_provider];
+            retry_mint => restrict_to: [admin_role];
             seed_used => PUBLIC;
+            preview => PUBLIC;
+            render => PUBLIC;
+            layer_weight => PUBLIC;
+            rarity => PUBLIC;
+            update_rarity_score => restrict_to: [admin_role];
+            mint_status => PUBLIC;
+            set_mint_price => restrict_to: [admin_role];
+            set_phase => restrict_to: [admin_role];
+            set_layer_weight => restrict_to: [admin_role];
+            reset_layer_weight => restrict_to: [admin_role];
+            mint_allowlist_badge => restrict_to: [admin_role];
+            withdraw_fees => restrict_to: [OWNER];
             mint_admin_badge => restrict_to: [OWNER];
         }
     }
@@ -31,10 +86,33 @@ mod svgenesis {
         existing_hashes: KeyValueStore<Hash, NonFungibleLocalId>,
         owner_badge: ResourceAddress,
         admin_badge: ResourceAddress,
+        allowlist_badge: ResourceAddress,
+        mint_price: Option<Decimal>,
+        fees: Vault,
+        max_supply: Option<u64>,
+        phase: MintPhase,
+        random_component: Option<ComponentAddress>,
+        pending_mints: KeyValueStore<u32, NonFungibleLocalId>,
+        storage_mode: StorageMode,
+        layer_weights: KeyValueStore<String, u32>,
+        trait_counts: KeyValueStore<String, u64>,
+        counted_nfts: u64,
     }
 
     impl SVGenesis {
-        pub fn instantiate() -> (Global<SVGenesis>, FungibleBucket) {
+        pub fn instantiate(
+            mint_price: Option<Decimal>,
+            max_supply: Option<u64>,
+            random_component: Option<ComponentAddress>,
+            storage_mode: StorageMode,
+        ) -> (Global<SVGenesis>, FungibleBucket) {
+            // Make sure the mint price and supply make sense
+            if let Some(price) = mint_price {
+                ensure(!price.is_negative(), SVGenesisError::NegativeMintPrice);
+            }
+
+            ensure(max_supply != Some(0), SVGenesisError::ZeroMaxSupply);
+
             let (address_reservation, component_address) =
                 Runtime::allocate_component_address(SVGenesis::blueprint_id());
 
@@ -86,6 +164,29 @@ mod svgenesis {
             ))
             .create_with_no_initial_supply();
 
+            // Create allowlist badge, which is required to mint during the allowlist phase
+            let allowlist_badge_manager = ResourceBuilder::new_fungible(OwnerRole::Updatable(
+                rule!(require(owner_badge.resource_address())),
+            ))
+            .divisibility(DIVISIBILITY_NONE)
+            .metadata(metadata! {
+                init {
+                    "name" => "SVGenesis allowlist badge", locked;
+                    "symbol" => "SVGALW", locked;
+                    "description" => "An allowlist badge for the SVGenesis NFT collection.", locked;
+                    "icon_url" => Url::of("https://i.ibb.co/gJY74HX/svgenesis.png"), locked;
+                }
+            })
+            .mint_roles(mint_roles!(
+                minter => rule!(require(global_caller(component_address)));
+                minter_updater => rule!(deny_all);
+            ))
+            .burn_roles(burn_roles!(
+                burner => rule!(allow_all);
+                burner_updater => OWNER;
+            ))
+            .create_with_no_initial_supply();
+
             // Metadata setter rule, we allow the admin to update metadata too to update the dApp definition
             let metadata_setter_rule = rule!(
                 require(owner_badge.resource_address()) || require(admin_badge_manager.address())
@@ -101,6 +202,10 @@ mod svgenesis {
                     burner => rule!(deny_all);
                     burner_updater => rule!(deny_all);
                 ))
+                .non_fungible_data_update_roles(non_fungible_data_update_roles!(
+                    non_fungible_data_updater => rule!(require(global_caller(component_address)));
+                    non_fungible_data_updater_updater => OWNER;
+                ))
                 .metadata(metadata! {
                     roles {
                         metadata_setter => metadata_setter_rule.clone();
@@ -117,6 +222,12 @@ mod svgenesis {
                 })
                 .create_with_no_initial_supply();
 
+            // Only the RandomComponent is allowed to deliver randomness, if there is one
+            let random_provider_rule = match random_component {
+                Some(random_component) => rule!(require(global_caller(random_component))),
+                None => rule!(deny_all),
+            };
+
             // Instantiate the component
             let svgenesis_component = Self {
                 svgenesis_manager,
@@ -127,6 +238,17 @@ mod svgenesis {
                     KeyValueStore::<Hash, NonFungibleLocalId>::new_with_registered_type(),
                 owner_badge: owner_badge.resource_address(),
                 admin_badge: admin_badge_manager.address(),
+                allowlist_badge: allowlist_badge_manager.address(),
+                mint_price,
+                fees: Vault::new(XRD),
+                max_supply,
+                phase: MintPhase::Public,
+                random_component,
+                pending_mints: KeyValueStore::<u32, NonFungibleLocalId>::new_with_registered_type(),
+                storage_mode,
+                layer_weights: KeyValueStore::<String, u32>::new_with_registered_type(),
+                trait_counts: KeyValueStore::<String, u64>::new_with_registered_type(),
+                counted_nfts: 0,
             }
             .instantiate()
             .prepare_to_globalize(OwnerRole::Updatable(rule!(require(
@@ -134,6 +256,7 @@ mod svgenesis {
             ))))
             .roles(roles! {
                 admin_role => rule!(require(admin_badge_manager.address()));
+                random_provider => random_provider_rule;
             })
             .metadata(metadata! (
                 roles {
@@ -154,82 +277,781 @@ mod svgenesis {
             (svgenesis_component, owner_badge)
         }
 
-        /// Mints an SVGenesis NFT using the provided seed.
+        /// Mints an SVGenesis NFT using the provided seed. If a mint price is set, it is taken from the payment.
         ///
-        /// Returns a Bucket containing the minted NFT.
+        /// Returns a Bucket containing the minted NFT and a Bucket with the change.
         ///
         /// Panics if:
-        /// * The seed is not a multiple of 4
+        /// * The current phase does not allow minting, or the caller is not allowlisted during the allowlist phase
+        /// * The supply is exhausted
+        /// * The payment is not in XRD or does not cover the mint price
+        /// * The seed is empty or not a multiple of 4
         /// * The seed is already used
         /// * The resulting SVG data already exists
-        pub fn mint_nft(&mut self, seed: Vec<u8>) -> Bucket {
-            // Make sure seed length is multiple of 4
-            assert!(seed.len() % 4 == 0, "Seed length must be a multiple of 4!");
+        pub fn mint_nft(&mut self, seed: Vec<u8>, payment: Bucket) -> (Bucket, Bucket) {
+            // Make sure we're allowed to mint
+            self.assert_can_mint(1);
 
-            // Make sure we can't reuse seeds
-            assert!(
-                self.used_seeds.get(&seed).is_none(),
-                "Seed was already used! Try another one :)"
+            // Take the mint price from the payment
+            let change = self.collect_payment(payment, 1);
+
+            // Generate our SVG data and make sure the seed and image are unique
+            let (nft_image_data, layers, traits, fingerprint) = self
+                .generate_unique_image(&seed)
+                .unwrap_or_else(|error| error.panic());
+
+            // Mint the NFT
+            let nft_bucket = self.mint_image(seed, nft_image_data, layers, traits, fingerprint);
+            self.update_sold_out();
+
+            (nft_bucket, change)
+        }
+
+        /// Mints an SVGenesis NFT for every provided seed. If a mint price is set, it is taken from the payment for every NFT.
+        ///
+        /// Returns a Bucket containing the minted NFTs and a Bucket with the change.
+        ///
+        /// Panics if any of the seeds would make `mint_nft` panic, or if a seed or resulting image occurs twice in the batch.
+        /// The panic message contains the index of the failing seed.
+        pub fn mint_nfts(&mut self, seeds: Vec<Vec<u8>>, payment: Bucket) -> (Bucket, Bucket) {
+            ensure(!seeds.is_empty(), SVGenesisError::NoSeeds);
+
+            // Make sure we're allowed to mint the whole batch
+            let nft_count = seeds.len() as u64;
+            self.assert_can_mint(nft_count);
+
+            // Take the mint price for the whole batch from the payment
+            let change = self.collect_payment(payment, nft_count);
+
+            // Generate all images before minting anything, checking uniqueness against both storage and the batch itself
+            let mut batch_seeds: HashSet<Vec<u8>> = HashSet::new();
+            let mut batch_hashes: HashSet<Hash> = HashSet::new();
+            let mut images = Vec::with_capacity(seeds.len());
+
+            for (index, seed) in seeds.into_iter().enumerate() {
+                let batch_seed_error = |error: SVGenesisError| SVGenesisError::InvalidBatchSeed {
+                    index,
+                    error: Box::new(error),
+                };
+
+                ensure(
+                    batch_seeds.insert(seed.clone()),
+                    batch_seed_error(SVGenesisError::DuplicateSeedInBatch),
+                );
+
+                let (nft_image_data, layers, traits, fingerprint) = self
+                    .generate_unique_image(&seed)
+                    .unwrap_or_else(|error| batch_seed_error(error).panic());
+
+                ensure(
+                    batch_hashes.insert(fingerprint),
+                    batch_seed_error(SVGenesisError::DuplicateImageInBatch),
+                );
+
+                images.push((seed, nft_image_data, layers, traits, fingerprint));
+            }
+
+            // Mint the NFTs
+            let mut nft_bucket = Bucket::new(self.svgenesis_manager.address());
+            for (seed, nft_image_data, layers, traits, fingerprint) in images {
+                nft_bucket.put(self.mint_image(seed, nft_image_data, layers, traits, fingerprint));
+            }
+            self.update_sold_out();
+
+            (nft_bucket, change)
+        }
+
+        /// Mints a placeholder SVGenesis NFT and requests verifiable randomness from the RandomComponent to generate its image.
+        /// If a mint price is set, it is taken from the payment.
+        ///
+        /// Returns a Bucket containing the placeholder NFT and a Bucket with the change. The image is filled in by `finish_mint`.
+        ///
+        /// Panics if:
+        /// * No RandomComponent was configured
+        /// * The current phase does not allow minting, or the caller is not allowlisted during the allowlist phase
+        /// * The supply is exhausted
+        /// * The payment is not in XRD or does not cover the mint price
+        pub fn request_mint(&mut self, payment: Bucket) -> (Bucket, Bucket) {
+            let random_component = self
+                .random_component
+                .unwrap_or_else(|| SVGenesisError::RandomnessNotEnabled.panic());
+
+            // Make sure we're allowed to mint
+            self.assert_can_mint(1);
+
+            // Take the mint price from the payment
+            let change = self.collect_payment(payment, 1);
+
+            // Mint the placeholder NFT
+            let nft_id = NonFungibleLocalId::integer(self.next_nft_id);
+            let nft_bucket = self.svgenesis_manager.mint_non_fungible::<SVGenesisNFT>(
+                &nft_id,
+                SVGenesisNFT {
+                    key_image_url: Url::of(Self::svg_data_uri(
+                        &nft_generator::generate_placeholder_image_data(),
+                    )),
+                    name: format!("SVGenesis #{}", self.next_nft_id),
+                    seed_lossy: String::new(),
+                    layers: vec![],
+                    traits: vec![],
+                    svg_data: SVGData::Empty,
+                    seed: vec![],
+                    generator_version: nft_generator::GENERATOR_VERSION,
+                    rarity_score: None,
+                },
             );
 
+            // Request the randomness, using the NFT id as the key
+            let key = u32::try_from(self.next_nft_id)
+                .unwrap_or_else(|_| SVGenesisError::NftIdTooLarge.panic());
+            self.pending_mints.insert(key, nft_id);
+            Self::request_random(random_component, key);
+
+            // Increment our NFT id counter for the next mint
+            self.next_nft_id += 1;
+            self.update_sold_out();
+
+            (nft_bucket, change)
+        }
+
+        /// Callback for the RandomComponent. Generates the image for a pending mint using the provided randomness.
+        ///
+        /// If the randomness results in a used seed or an existing image, a new seed is derived from it.
+        ///
+        /// Panics if there is no pending mint for the key or no unique image could be generated
+        pub fn finish_mint(&mut self, key: u32, random_seed: Vec<u8>) {
+            let nft_id = self
+                .pending_mints
+                .remove(&key)
+                .unwrap_or_else(|| SVGenesisError::NoPendingMint.panic());
+
+            // Derive a new seed by hashing the current one until we find a unique image
+            let mut seed = random_seed;
+            let mut attempts = 0;
+            let (nft_image_data, layers, traits, fingerprint) = loop {
+                match self.generate_unique_image(&seed) {
+                    Ok(image) => break image,
+                    Err(error) => {
+                        attempts += 1;
+                        ensure(
+                            attempts < 10,
+                            SVGenesisError::NoUniqueImage {
+                                error: Box::new(error),
+                            },
+                        );
+
+                        seed = hash(&seed).0.to_vec();
+                    }
+                }
+            };
+
+            // Replace the placeholder data with the generated image
+            self.update_image(nft_id, seed, nft_image_data, layers, traits, fingerprint);
+        }
+
+        /// Error callback for the RandomComponent. The mint stays pending, so it can be retried by an admin.
+        pub fn abort_mint(&mut self, key: u32) {
+            ensure(
+                self.pending_mints.get(&key).is_some(),
+                SVGenesisError::NoPendingMint,
+            );
+        }
+
+        /// Requests randomness for a pending mint again, for example after the RandomComponent called `abort_mint`.
+        ///
+        /// Panics if there is no pending mint for the key
+        pub fn retry_mint(&mut self, key: u32) {
+            ensure(
+                self.pending_mints.get(&key).is_some(),
+                SVGenesisError::NoPendingMint,
+            );
+
+            Self::request_random(
+                self.random_component
+                    .unwrap_or_else(|| SVGenesisError::RandomnessNotEnabled.panic()),
+                key,
+            );
+        }
+
+        /// Regenerates the image of an SVGenesis NFT using a new seed. The NFT keeps its id.
+        ///
+        /// Returns a Bucket containing the NFT with its new image.
+        ///
+        /// Panics if:
+        /// * The bucket does not contain exactly one SVGenesis NFT
+        /// * The NFT is still waiting for its randomness
+        /// * The seed is empty or not a multiple of 4
+        /// * The seed is already used
+        /// * The resulting SVG data already exists
+        pub fn reroll(&mut self, nft: Bucket, seed: Vec<u8>) -> Bucket {
+            ensure(
+                nft.resource_address() == self.svgenesis_manager.address(),
+                SVGenesisError::NotAnSVGenesisNft,
+            );
+            ensure(nft.amount() == dec!(1), SVGenesisError::InvalidRerollAmount);
+
+            let nft_id = nft.as_non_fungible().non_fungible_local_id();
+            let old_nft_data = self
+                .svgenesis_manager
+                .get_non_fungible_data::<SVGenesisNFT>(&nft_id);
+            ensure(!old_nft_data.seed.is_empty(), SVGenesisError::ImagePending);
+
+            // Generate the new image and make sure the seed and image are unique
+            let (nft_image_data, layers, traits, fingerprint) = self
+                .generate_unique_image(&seed)
+                .unwrap_or_else(|error| error.panic());
+
+            // Free up the old image, so it can be generated again. The old seed stays used.
+            let old_fingerprint =
+                Self::fingerprint(&self.stored_image(&old_nft_data), &old_nft_data.layers);
+            self.existing_hashes.remove(&old_fingerprint);
+            self.remove_from_trait_counts(&old_nft_data.traits);
+
+            // Replace the old data with the new image
+            self.update_image(nft_id, seed, nft_image_data, layers, traits, fingerprint);
+
+            nft
+        }
+
+        /// Checks if a seed was used.
+        ///
+        /// Returns a tuple with a bool and optionally a NonFungibleLocalId for the NFT that was minted with this seed.
+        pub fn seed_used(&self, seed: Vec<u8>) -> (bool, Option<NonFungibleLocalId>) {
+            match self.used_seeds.get(&seed) {
+                Some(nflid) => (true, Some(nflid.to_owned())),
+                None => (false, None),
+            }
+        }
+
+        /// Renders the image for a seed without minting it.
+        ///
+        /// Returns the SVG data URI, the layer names and whether the seed or the resulting image already exists.
+        ///
+        /// Panics if the seed is empty or not a multiple of 4
+        pub fn preview(&self, seed: Vec<u8>) -> (String, Vec<String>, bool) {
+            Self::check_seed(&seed).unwrap_or_else(|error| error.panic());
+
             // Generate our SVG data
-            let (nft_image_data, layers) = nft_generator::generate_nft_image_data(&seed);
-            let url_encoded_nft_image_data = urlencoding::encode(&nft_image_data).into_owned();
-            let svg_data_uri = format!("data:image/svg+xml,{url_encoded_nft_image_data}");
-            let svg_data_hash = hash(nft_image_data.clone());
+            let (nft_image_data, layers) = nft_generator::generate_nft_image_data_with_weights(
+                &seed,
+                &StoredWeights(&self.layer_weights),
+            );
+
+            // Check whether the seed or image were already used
+            let exists = self.used_seeds.get(&seed).is_some()
+                || self
+                    .existing_hashes
+                    .get(&Self::fingerprint(&nft_image_data, &layers))
+                    .is_some();
+
+            (Self::svg_data_uri(&nft_image_data), layers, exists)
+        }
+
+        /// Returns the SVG data of an NFT. In compact storage mode, it is rebuilt from its seed and generator version.
+        ///
+        /// Panics if:
+        /// * The NFT does not exist
+        /// * The NFT is still waiting for its image
+        pub fn render(&self, nft_id: NonFungibleLocalId) -> String {
+            let nft_data = self
+                .svgenesis_manager
+                .get_non_fungible_data::<SVGenesisNFT>(&nft_id);
+            ensure(!nft_data.seed.is_empty(), SVGenesisError::ImagePending);
+
+            self.stored_image(&nft_data)
+        }
+
+        /// Returns the weight that is used to pick a layer. A weight of 0 means the layer is disabled.
+        ///
+        /// Panics if the layer does not exist
+        pub fn layer_weight(&self, layer_name: String) -> u32 {
+            let default_weight = Self::default_layer_weight(&layer_name);
+
+            StoredWeights(&self.layer_weights).weight(&layer_name, default_weight)
+        }
 
-            // Make sure hash does not yet exist
-            assert!(
-                self.existing_hashes.get(&svg_data_hash).is_none(),
-                "This image already exsists!"
+        /// Changes the weight that is used to pick a layer. Setting the weight to 0 disables the layer.
+        ///
+        /// Panics if:
+        /// * The layer does not exist
+        /// * The component uses compact storage mode, where NFTs are rendered with the default weights
+        /// * The weight is higher than `MAX_LAYER_WEIGHT`
+        /// * It would disable the last background, or the last big and small element
+        pub fn set_layer_weight(&mut self, layer_name: String, weight: u32) {
+            Self::default_layer_weight(&layer_name);
+            ensure(
+                self.storage_mode == StorageMode::Full,
+                SVGenesisError::LayerWeightsLocked,
+            );
+            ensure(
+                weight <= MAX_LAYER_WEIGHT,
+                SVGenesisError::LayerWeightTooHigh {
+                    max_weight: MAX_LAYER_WEIGHT,
+                },
             );
 
-            // Mint the NFT
+            self.layer_weights.insert(layer_name, weight);
+
+            // Every NFT needs a background and a second layer, which frames can't always provide because they depend
+            // on a base color. Without them, the generator would keep rolling until it runs out of fees.
+            ensure(
+                self.has_enabled_layer(&[LayerCategory::Background]),
+                SVGenesisError::NoBackgroundsLeft,
+            );
+            ensure(
+                self.has_enabled_layer(&[LayerCategory::BigElement, LayerCategory::SmallElement]),
+                SVGenesisError::NoElementsLeft,
+            );
+        }
+
+        /// Resets the weight of a layer to its default weight
+        ///
+        /// Panics if the layer does not exist
+        pub fn reset_layer_weight(&mut self, layer_name: String) {
+            Self::default_layer_weight(&layer_name);
+
+            self.layer_weights.remove(&layer_name);
+        }
+
+        /// Returns how common each trait of an NFT is among the minted NFTs, and the resulting rarity score.
+        /// Rarity changes as more NFTs are minted.
+        ///
+        /// Panics if:
+        /// * The NFT does not exist
+        /// * The NFT is still waiting for its image
+        pub fn rarity(&self, nft_id: NonFungibleLocalId) -> Rarity {
+            let nft_data = self
+                .svgenesis_manager
+                .get_non_fungible_data::<SVGenesisNFT>(&nft_id);
+            ensure(!nft_data.seed.is_empty(), SVGenesisError::ImagePending);
+
+            let total = Decimal::from(self.counted_nfts);
+            let frequencies: Vec<TraitFrequency> = Self::trait_keys(&nft_data.traits)
+                .into_iter()
+                .map(|trait_key| {
+                    let count = self.trait_count(&trait_key);
+                    TraitFrequency {
+                        trait_key,
+                        count,
+                        frequency: Decimal::from(count) / total,
+                    }
+                })
+                .collect();
+
+            let score = frequencies
+                .iter()
+                .map(|trait_frequency| total / Decimal::from(trait_frequency.count))
+                .fold(Decimal::ZERO, |score, inverse_frequency| {
+                    score + inverse_frequency
+                });
+
+            Rarity { frequencies, score }
+        }
+
+        /// Writes the current rarity score of an NFT into its data, so it can be shown by wallets and marketplaces.
+        ///
+        /// Returns the rarity score.
+        ///
+        /// Panics if:
+        /// * The NFT does not exist
+        /// * The NFT is still waiting for its image
+        pub fn update_rarity_score(&mut self, nft_id: NonFungibleLocalId) -> Decimal {
+            let score = self.rarity(nft_id.clone()).score;
+
+            self.svgenesis_manager
+                .update_non_fungible_data(&nft_id, "rarity_score", Some(score));
+
+            score
+        }
+
+        /// Returns the current mint phase, the number of minted NFTs and the max supply, if any.
+        pub fn mint_status(&self) -> (MintPhase, u64, Option<u64>) {
+            (self.phase, self.next_nft_id - 1, self.max_supply)
+        }
+
+        /// Moves the collection to another mint phase.
+        ///
+        /// Panics if the supply is exhausted and the phase is not SoldOut.
+        pub fn set_phase(&mut self, phase: MintPhase) {
+            ensure(
+                phase == MintPhase::SoldOut || !self.supply_exhausted(),
+                SVGenesisError::CannotReopenMint,
+            );
+
+            self.phase = phase;
+        }
+
+        /// Mints an allowlist badge
+        ///
+        /// Returns a Bucket with the allowlist badge
+        pub fn mint_allowlist_badge(&mut self) -> Bucket {
+            ResourceManager::from_address(self.allowlist_badge).mint(1)
+        }
+
+        /// Sets the price per mint in XRD. Passing None makes minting free.
+        ///
+        /// Panics if the price is negative.
+        pub fn set_mint_price(&mut self, mint_price: Option<Decimal>) {
+            if let Some(price) = mint_price {
+                ensure(!price.is_negative(), SVGenesisError::NegativeMintPrice);
+            }
+
+            self.mint_price = mint_price;
+        }
+
+        /// Withdraws all collected mint fees
+        ///
+        /// Returns a Bucket with the XRD
+        pub fn withdraw_fees(&mut self) -> Bucket {
+            self.fees.take_all()
+        }
+
+        /// Mints an admin badge
+        ///
+        /// Returns a Bucket with the admin badge
+        pub fn mint_admin_badge(&mut self) -> Bucket {
+            ResourceManager::from_address(self.admin_badge).mint(1)
+        }
+
+        /// Makes sure the current phase allows minting and enough supply is left for the requested amount of NFTs
+        fn assert_can_mint(&self, amount: u64) {
+            match self.phase {
+                MintPhase::Closed => SVGenesisError::MintClosed.panic(),
+                MintPhase::SoldOut => SVGenesisError::SoldOut.panic(),
+                MintPhase::Allowlist => {
+                    Runtime::assert_access_rule(rule!(require(self.allowlist_badge)))
+                }
+                MintPhase::Public => {}
+            }
+
+            if let Some(max_supply) = self.max_supply {
+                let remaining = max_supply - (self.next_nft_id - 1);
+                ensure(
+                    amount <= remaining,
+                    SVGenesisError::SupplyExhausted { remaining },
+                );
+            }
+        }
+
+        /// Checks whether the max supply was reached
+        fn supply_exhausted(&self) -> bool {
+            self.max_supply
+                .is_some_and(|max_supply| self.next_nft_id - 1 >= max_supply)
+        }
+
+        /// Moves the collection to the SoldOut phase once the max supply was reached
+        fn update_sold_out(&mut self) {
+            if self.supply_exhausted() {
+                self.phase = MintPhase::SoldOut;
+            }
+        }
+
+        /// Generates the image for a seed and makes sure both the seed and the image are unique
+        ///
+        /// Returns the SVG data, the layer names, the traits and the fingerprint of the image, or an error message
+        fn generate_unique_image(
+            &self,
+            seed: &Vec<u8>,
+        ) -> Result<(String, Vec<String>, Vec<SVGenesisTrait>, Hash), SVGenesisError> {
+            Self::check_seed(seed)?;
+
+            // Make sure we can't reuse seeds
+            if self.used_seeds.get(seed).is_some() {
+                return Err(SVGenesisError::SeedUsed);
+            }
+
+            // Compose the image and render our SVG data
+            let composition = nft_generator::compose_nft_image_with_weights(
+                seed,
+                &StoredWeights(&self.layer_weights),
+            );
+            let nft_image_data = nft_generator::render_composition(
+                &composition,
+                nft_generator::GeneratorVersion::CURRENT,
+            );
+            let layers = composition.layer_names();
+            let traits = composition
+                .traits()
+                .into_iter()
+                .map(SVGenesisTrait::from)
+                .collect();
+            let fingerprint = Self::fingerprint(&nft_image_data, &layers);
+
+            // Make sure no visually identical image exists
+            if self.existing_hashes.get(&fingerprint).is_some() {
+                return Err(SVGenesisError::DuplicateImage);
+            }
+
+            Ok((nft_image_data, layers, traits, fingerprint))
+        }
+
+        /// Makes sure a seed can be used by .Random. An empty seed is also rejected, because NFTs that are waiting for
+        /// their image are recognized by their empty seed.
+        fn check_seed(seed: &[u8]) -> Result<(), SVGenesisError> {
+            if seed.is_empty() {
+                return Err(SVGenesisError::EmptySeed);
+            }
+
+            // Make sure seed length is multiple of 4
+            if seed.len() % 4 != 0 {
+                return Err(SVGenesisError::InvalidSeedLength);
+            }
+
+            Ok(())
+        }
+
+        /// Mints an NFT with the provided image and registers its seed and hash
+        ///
+        /// Returns a Bucket containing the minted NFT
+        fn mint_image(
+            &mut self,
+            seed: Vec<u8>,
+            nft_image_data: String,
+            layers: Vec<String>,
+            traits: Vec<SVGenesisTrait>,
+            fingerprint: Hash,
+        ) -> Bucket {
             let nft_id = NonFungibleLocalId::integer(self.next_nft_id);
+            let key_image_url = self.key_image_url(&nft_id, &nft_image_data);
+
+            // Mint the NFT
             let nft_bucket = self.svgenesis_manager.mint_non_fungible::<SVGenesisNFT>(
                 &nft_id,
                 SVGenesisNFT {
-                    key_image_url: Url::of(svg_data_uri.clone()),
+                    key_image_url: key_image_url.clone(),
                     name: format!("SVGenesis #{}", self.next_nft_id),
                     // Can't guarantee that all characters will be valid UTF-8, so this is basically best-effort and for fun if someone wants to use their own vanity seed
                     seed_lossy: String::from_utf8_lossy(&seed).into_owned(),
                     layers,
-                    svg_data: hex::encode(nft_image_data),
+                    traits: traits.clone(),
+                    svg_data: self.stored_svg_data(nft_image_data),
+                    seed: seed.clone(),
+                    generator_version: nft_generator::GENERATOR_VERSION,
+                    rarity_score: None,
                 },
             );
 
+            self.register_image(nft_id, seed, key_image_url, traits, fingerprint);
+
+            // Increment our NFT id counter for the next mint
+            self.next_nft_id += 1;
+
+            nft_bucket
+        }
+
+        /// Replaces the image of an existing NFT and registers its seed and hash
+        fn update_image(
+            &mut self,
+            nft_id: NonFungibleLocalId,
+            seed: Vec<u8>,
+            nft_image_data: String,
+            layers: Vec<String>,
+            traits: Vec<SVGenesisTrait>,
+            fingerprint: Hash,
+        ) {
+            let key_image_url = self.key_image_url(&nft_id, &nft_image_data);
+
+            self.svgenesis_manager.update_non_fungible_data(
+                &nft_id,
+                "key_image_url",
+                key_image_url.clone(),
+            );
+            self.svgenesis_manager.update_non_fungible_data(
+                &nft_id,
+                "seed_lossy",
+                String::from_utf8_lossy(&seed).into_owned(),
+            );
+            self.svgenesis_manager
+                .update_non_fungible_data(&nft_id, "layers", layers);
+            self.svgenesis_manager
+                .update_non_fungible_data(&nft_id, "traits", traits.clone());
+            self.svgenesis_manager.update_non_fungible_data(
+                &nft_id,
+                "svg_data",
+                self.stored_svg_data(nft_image_data),
+            );
+            self.svgenesis_manager
+                .update_non_fungible_data(&nft_id, "seed", seed.clone());
+            self.svgenesis_manager.update_non_fungible_data(
+                &nft_id,
+                "generator_version",
+                nft_generator::GENERATOR_VERSION,
+            );
+            self.svgenesis_manager.update_non_fungible_data(
+                &nft_id,
+                "rarity_score",
+                None::<Decimal>,
+            );
+
+            self.register_image(nft_id, seed, key_image_url, traits, fingerprint);
+        }
+
+        /// Emits the mint event for a generated image and registers its seed and hash
+        fn register_image(
+            &mut self,
+            nft_id: NonFungibleLocalId,
+            seed: Vec<u8>,
+            key_image_url: Url,
+            traits: Vec<SVGenesisTrait>,
+            fingerprint: Hash,
+        ) {
+            self.add_to_trait_counts(&traits);
+
             // Generate mint event
             Runtime::emit_event(Generation {
-                key_image_url: Url::of(svg_data_uri.clone()),
+                key_image_url,
                 seed_lossy: String::from_utf8_lossy(&seed).into_owned(),
-                non_fungible_local_id: NonFungibleLocalId::from(self.next_nft_id),
+                non_fungible_local_id: nft_id.clone(),
+                traits,
             });
 
             // Add the hash, seed and NonFungibleLocalId to the used_seeds and existing_hashes KeyValueStores
             self.used_seeds.insert(seed, nft_id.clone());
-            self.existing_hashes.insert(svg_data_hash, nft_id.clone());
+            self.existing_hashes.insert(fingerprint, nft_id);
+        }
 
-            // Increment our NFT id counter for the next mint
-            self.next_nft_id += 1;
+        /// Returns the keys of all traits of an NFT, counting a trait only once if multiple layers share it
+        fn trait_keys(traits: &[SVGenesisTrait]) -> IndexSet<String> {
+            traits
+                .iter()
+                .flat_map(|nft_trait| nft_trait.keys())
+                .collect()
+        }
 
-            nft_bucket
+        /// Returns the number of minted NFTs that have a trait
+        fn trait_count(&self, trait_key: &String) -> u64 {
+            self.trait_counts
+                .get(trait_key)
+                .map(|count| *count)
+                .unwrap_or(0)
         }
 
-        /// Checks if a seed was used.
+        /// Counts the traits of a newly generated image
+        fn add_to_trait_counts(&mut self, traits: &[SVGenesisTrait]) {
+            for trait_key in Self::trait_keys(traits) {
+                let count = self.trait_count(&trait_key);
+                self.trait_counts.insert(trait_key, count + 1);
+            }
+
+            self.counted_nfts += 1;
+        }
+
+        /// Stops counting the traits of an image that was replaced
+        fn remove_from_trait_counts(&mut self, traits: &[SVGenesisTrait]) {
+            for trait_key in Self::trait_keys(traits) {
+                let count = self.trait_count(&trait_key);
+                self.trait_counts.insert(trait_key, count - 1);
+            }
+
+            self.counted_nfts -= 1;
+        }
+
+        /// Requests randomness from the RandomComponent, which calls back `finish_mint` or `abort_mint` with the provided key
+        fn request_random(random_component: ComponentAddress, key: u32) {
+            Global::<AnyComponent>::from(random_component).call_raw::<u32>(
+                "request_random",
+                scrypto_args!(
+                    Runtime::global_address(),
+                    "finish_mint".to_string(),
+                    "abort_mint".to_string(),
+                    key,
+                    None::<FungibleBucket>,
+                    0u8
+                ),
+            );
+        }
+
+        /// Returns the key_image_url for an NFT, which is either the SVG data URI or a link to the off-ledger renderer in compact storage mode
+        fn key_image_url(&self, nft_id: &NonFungibleLocalId, nft_image_data: &str) -> Url {
+            match &self.storage_mode {
+                StorageMode::Full => Url::of(Self::svg_data_uri(nft_image_data)),
+                StorageMode::Compact { image_base_url } => match nft_id {
+                    NonFungibleLocalId::Integer(id) => {
+                        Url::of(format!("{}{}", image_base_url, id.value()))
+                    }
+                    _ => SVGenesisError::NonIntegerNftId.panic(),
+                },
+            }
+        }
+
+        /// Returns the svg_data to store on an NFT, which is left empty in compact storage mode
+        fn stored_svg_data(&self, nft_image_data: String) -> SVGData {
+            match self.storage_mode {
+                StorageMode::Full => SVGData::encode(&nft_image_data),
+                StorageMode::Compact { .. } => SVGData::Empty,
+            }
+        }
+
+        /// Checks whether any layer in the categories can still be picked
+        fn has_enabled_layer(&self, categories: &[LayerCategory]) -> bool {
+            let weights = StoredWeights(&self.layer_weights);
+
+            layers::all_layers()
+                .iter()
+                .filter(|(layer, _)| categories.contains(&layer.category()))
+                .any(|(layer, default_weight)| weights.weight(layer.id(), *default_weight) > 0)
+        }
+
+        /// Returns the default weight of a layer
         ///
-        /// Returns a tuple with a bool and optionally a NonFungibleLocalId for the NFT that was minted with this seed.
-        pub fn seed_used(&self, seed: Vec<u8>) -> (bool, Option<NonFungibleLocalId>) {
-            match self.used_seeds.get(&seed) {
-                Some(nflid) => (true, Some(nflid.to_owned())),
-                None => (false, None),
+        /// Panics if the layer does not exist
+        fn default_layer_weight(layer_name: &str) -> u32 {
+            layers::all_layers()
+                .into_iter()
+                .find(|(layer, _)| layer.id() == layer_name)
+                .map(|(_, default_weight)| default_weight)
+                .unwrap_or_else(|| SVGenesisError::UnknownLayer.panic())
+        }
+
+        /// Returns the SVG data of a generated NFT, either from its svg_data or rebuilt from its seed in compact storage mode
+        fn stored_image(&self, nft_data: &SVGenesisNFT) -> String {
+            match self.storage_mode {
+                StorageMode::Full => nft_data
+                    .svg_data
+                    .decode()
+                    .unwrap_or_else(|| SVGenesisError::InvalidSVGData.panic()),
+                StorageMode::Compact { .. } => {
+                    nft_generator::render_nft_image(&nft_data.seed, nft_data.generator_version)
+                        .unwrap_or_else(|| {
+                            SVGenesisError::UnsupportedGeneratorVersion {
+                                generator_version: nft_data.generator_version,
+                            }
+                            .panic()
+                        })
+                }
             }
         }
 
-        /// Mints an admin badge
+        /// Hashes the canonical fingerprint of an image, which is the same for visually identical images
+        fn fingerprint(nft_image_data: &str, layers: &[String]) -> Hash {
+            hash(nft_generator::canonical_fingerprint(nft_image_data, layers))
+        }
+
+        /// Turns SVG data into a data URI that can be used as a key_image_url
+        fn svg_data_uri(nft_image_data: &str) -> String {
+            data_uri::svg_data_uri(nft_image_data)
+        }
+
+        /// Takes the mint price for the provided amount of NFTs from the payment, if there is one, and puts it in the fees vault
         ///
-        /// Returns a Bucket with the admin badge
-        pub fn mint_admin_badge(&mut self) -> Bucket {
-            ResourceManager::from_address(self.admin_badge).mint(1)
+        /// Returns the change
+        fn collect_payment(&mut self, mut payment: Bucket, nft_count: u64) -> Bucket {
+            if let Some(mint_price) = self.mint_price {
+                let total_price = mint_price * Decimal::from(nft_count);
+
+                ensure(
+                    payment.resource_address() == XRD,
+                    SVGenesisError::WrongPaymentResource,
+                );
+                ensure(
+                    payment.amount() >= total_price,
+                    SVGenesisError::InsufficientPayment { mint_price },
+                );
+
+                self.fees.put(payment.take(total_price));
+            }
+
+            payment
         }
     }
 }
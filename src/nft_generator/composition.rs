@@ -0,0 +1,479 @@
+use crate::hsl::*;
+use crate::layers::{Layer, LayerCategory};
+use std::collections::BTreeMap;
+use svg::node::element::Element;
+use svg::node::{Attributes, Children};
+use svg::Node;
+
+/// An NFT image with all of its random choices resolved, before it's rendered to SVG.
+///
+/// It can be inspected without parsing any SVG, and `renderer::render()` turns it into the SVG document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Composition {
+    pub base_color: Option<HSL>,
+    /// The layers, from the bottom to the top
+    pub layers: Vec<ComposedLayer>,
+    /// The gradients and patterns of all layers, in the order the layers defined them. This includes definitions no
+    /// shape refers to and definitions with an id that was already used, as v1 renders them all. Like in browsers, a
+    /// shape refers to the first definition with its id.
+    pub definitions: Vec<Paint>,
+}
+
+/// A layer and the shapes it generated
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComposedLayer {
+    pub id: String,
+    /// A human-readable name for the layer, like "Star Shadow"
+    pub name: String,
+    pub category: LayerCategory,
+    pub shapes: Vec<Shape>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shape {
+    pub kind: ShapeKind,
+    /// The remaining attributes, mostly the ones describing its size and position like `width`, `r` or `d`
+    pub attributes: BTreeMap<String, String>,
+    /// The transforms in the order they are applied, e.g. a rotation
+    pub transforms: Vec<Transform>,
+    pub fill: Option<Paint>,
+    pub stroke: Option<Paint>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ShapeKind {
+    Rectangle,
+    Circle,
+    Ellipse,
+    Path,
+    Polygon,
+    Other(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transform {
+    /// A rotation in degrees, around the center if it's provided
+    Rotate {
+        degrees: i32,
+        center: Option<(i32, i32)>,
+    },
+    Translate {
+        x: i32,
+        y: i32,
+    },
+}
+
+/// What a shape is filled or stroked with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Paint {
+    Color(HSL),
+    LinearGradient(Gradient),
+    RadialGradient(Gradient),
+    Pattern(Pattern),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gradient {
+    /// The id the gradient is referred to by. It is randomly generated, so it doesn't say anything about how it looks.
+    pub id: String,
+    pub stops: Vec<GradientStop>,
+    /// The rotation of the gradient in degrees
+    pub angle: Option<u16>,
+    /// Any other attributes
+    pub attributes: BTreeMap<String, String>,
+    /// Whether the gradient is written inside its own nested `<defs>` element. The pattern backgrounds of v1 do this
+    /// for the gradients of their tiles, so it's kept to render them identically. Like in `utils`, a nested `<defs>`
+    /// holds a single gradient.
+    pub nested: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GradientStop {
+    pub offset: String,
+    pub color: HSL,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    /// The id the pattern is referred to by. It is randomly generated, so it doesn't say anything about how it looks.
+    pub id: String,
+    /// The remaining attributes, like the size of the tile
+    pub attributes: BTreeMap<String, String>,
+    pub transforms: Vec<Transform>,
+    /// The shapes that make up a single tile
+    pub shapes: Vec<Shape>,
+}
+
+impl Composition {
+    /// Builds the composition from the layers and the SVG elements they generated
+    pub fn from_layers(
+        base_color: Option<HSL>,
+        generated_layers: Vec<(Box<dyn Layer>, Vec<Element>)>,
+    ) -> Self {
+        // Collect the definitions of all layers first, so fills can be resolved
+        let mut definitions: Definitions = vec![];
+        for (_, elements) in &generated_layers {
+            for element in elements.iter().filter(|element| is_definitions(element)) {
+                collect_definitions(element, false, &mut definitions);
+            }
+        }
+
+        let layers = generated_layers
+            .iter()
+            .map(|(layer, elements)| ComposedLayer {
+                id: layer.layer_name(),
+                name: layer.display_name(),
+                category: layer.category(),
+                shapes: elements
+                    .iter()
+                    .filter(|element| !is_definitions(element))
+                    .map(|element| Shape::from_node(element, &definitions))
+                    .collect(),
+            })
+            .collect();
+
+        Composition {
+            base_color,
+            layers,
+            definitions: definitions
+                .iter()
+                .map(|(_, definition, nested)| definition_paint(*definition, *nested, &definitions))
+                .collect(),
+        }
+    }
+
+    /// Returns only the definitions that shapes refer to, each id once, in the order they are first used. Definitions
+    /// come before the patterns that use them.
+    pub fn referenced_definitions(&self) -> Vec<Paint> {
+        let mut referenced_definitions = vec![];
+        for shape in self.layers.iter().flat_map(|layer| layer.shapes.iter()) {
+            add_referenced_definitions(shape, &mut referenced_definitions);
+        }
+
+        referenced_definitions
+    }
+
+    /// The names of the layers, which are stored on the NFT
+    pub fn layer_names(&self) -> Vec<String> {
+        self.layers.iter().map(|layer| layer.id.clone()).collect()
+    }
+}
+
+impl ShapeKind {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "rect" => ShapeKind::Rectangle,
+            "circle" => ShapeKind::Circle,
+            "ellipse" => ShapeKind::Ellipse,
+            "path" => ShapeKind::Path,
+            "polygon" => ShapeKind::Polygon,
+            _ => ShapeKind::Other(name.to_string()),
+        }
+    }
+
+    /// The name of the SVG element
+    pub fn name(&self) -> &str {
+        match self {
+            ShapeKind::Rectangle => "rect",
+            ShapeKind::Circle => "circle",
+            ShapeKind::Ellipse => "ellipse",
+            ShapeKind::Path => "path",
+            ShapeKind::Polygon => "polygon",
+            ShapeKind::Other(name) => name,
+        }
+    }
+}
+
+impl Shape {
+    fn from_node(node: &dyn Node, definitions: &Definitions) -> Self {
+        let mut attributes = attributes(node);
+        let transforms = take_transforms(&mut attributes, "transform");
+        let fill = take_paint(&mut attributes, "fill", definitions);
+        let stroke = take_paint(&mut attributes, "stroke", definitions);
+
+        Shape {
+            kind: ShapeKind::from_name(node.get_name()),
+            attributes,
+            transforms,
+            fill,
+            stroke,
+        }
+    }
+}
+
+impl Transform {
+    /// Parses a list of transforms, like `rotate(45, 500, 500) translate(5, 5)`
+    pub fn parse_list(value: &str) -> Option<Vec<Transform>> {
+        let transforms = value
+            .split(')')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let (name, arguments) = part.split_once('(')?;
+                let arguments = arguments
+                    .split(',')
+                    .map(|argument| argument.trim().parse::<i32>().ok())
+                    .collect::<Option<Vec<i32>>>()?;
+
+                match (name, arguments.as_slice()) {
+                    ("rotate", [degrees]) => Some(Transform::Rotate {
+                        degrees: *degrees,
+                        center: None,
+                    }),
+                    ("rotate", [degrees, x, y]) => Some(Transform::Rotate {
+                        degrees: *degrees,
+                        center: Some((*x, *y)),
+                    }),
+                    ("translate", [x, y]) => Some(Transform::Translate { x: *x, y: *y }),
+                    _ => None,
+                }
+            })
+            .collect::<Option<Vec<Transform>>>()?;
+
+        // Only accept it if nothing gets lost when writing it back
+        (Transform::list_as_string(&transforms) == value).then_some(transforms)
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            Transform::Rotate {
+                degrees,
+                center: None,
+            } => format!("rotate({degrees})"),
+            Transform::Rotate {
+                degrees,
+                center: Some((x, y)),
+            } => format!("rotate({degrees}, {x}, {y})"),
+            Transform::Translate { x, y } => format!("translate({x}, {y})"),
+        }
+    }
+
+    pub fn list_as_string(transforms: &[Transform]) -> String {
+        transforms
+            .iter()
+            .map(Transform::as_string)
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+impl Paint {
+    /// The id of the definition this paint refers to, if any
+    pub fn definition_id(&self) -> Option<&str> {
+        match self {
+            Paint::Color(_) => None,
+            Paint::LinearGradient(gradient) | Paint::RadialGradient(gradient) => Some(&gradient.id),
+            Paint::Pattern(pattern) => Some(&pattern.id),
+        }
+    }
+
+    /// The value of the `fill` or `stroke` attribute
+    pub fn as_string(&self) -> String {
+        match self {
+            Paint::Color(color) => color.as_string(),
+            _ => format!(
+                "url(#{})",
+                self.definition_id()
+                    .expect("A gradient or pattern always has an id.")
+            ),
+        }
+    }
+}
+
+impl Gradient {
+    fn from_node(node: &dyn Node, nested: bool) -> Self {
+        let mut attributes = attributes(node);
+        let id = attributes.remove("id").unwrap_or_default();
+
+        // The angle is stored as a rotation around the center of the gradient
+        let angle = attributes.get("gradientTransform").and_then(|transform| {
+            let angle = transform
+                .strip_prefix("rotate(")?
+                .strip_suffix(", 0.5, 0.5)")?
+                .parse::<u16>()
+                .ok()?;
+            (Gradient::transform_as_string(angle) == *transform).then_some(angle)
+        });
+        if angle.is_some() {
+            attributes.remove("gradientTransform");
+        }
+
+        let stops = children(node)
+            .iter()
+            .map(|stop| GradientStop {
+                offset: attribute(stop.as_ref(), "offset").unwrap_or_default(),
+                color: attribute(stop.as_ref(), "stop-color")
+                    .and_then(|color| parse_color(&color))
+                    .expect("Gradient stops must use HSL colors. This should never happen."),
+            })
+            .collect();
+
+        Gradient {
+            id,
+            stops,
+            angle,
+            attributes,
+            nested,
+        }
+    }
+
+    /// The value of the `gradientTransform` attribute for an angle
+    pub fn transform_as_string(angle: u16) -> String {
+        format!("rotate({angle}, 0.5, 0.5)")
+    }
+}
+
+impl Pattern {
+    fn from_node(node: &dyn Node, definitions: &Definitions) -> Self {
+        let mut attributes = attributes(node);
+        let id = attributes.remove("id").unwrap_or_default();
+        let transforms = take_transforms(&mut attributes, "patternTransform");
+
+        Pattern {
+            id,
+            attributes,
+            transforms,
+            shapes: children(node)
+                .iter()
+                .map(|child| Shape::from_node(child.as_ref(), definitions))
+                .collect(),
+        }
+    }
+}
+
+/// The definitions in document order with their id, and whether they are nested in another `<defs>` element
+type Definitions<'a> = Vec<(String, &'a dyn Node, bool)>;
+
+fn collect_definitions<'a>(node: &'a dyn Node, nested: bool, definitions: &mut Definitions<'a>) {
+    for definition in children(node) {
+        if definition.get_name() == "defs" {
+            collect_definitions(definition.as_ref(), true, definitions);
+        } else {
+            let id = attribute(definition.as_ref(), "id")
+                .expect("A definition must have an id. This should never happen.");
+            definitions.push((id, definition.as_ref(), nested));
+        }
+    }
+}
+
+/// Resolves a gradient or pattern definition
+fn definition_paint(definition: &dyn Node, nested: bool, definitions: &Definitions) -> Paint {
+    match definition.get_name() {
+        "linearGradient" => Paint::LinearGradient(Gradient::from_node(definition, nested)),
+        "radialGradient" => Paint::RadialGradient(Gradient::from_node(definition, nested)),
+        "pattern" => Paint::Pattern(Pattern::from_node(definition, definitions)),
+        name => panic!("Unsupported definition {name}. This should never happen."),
+    }
+}
+
+/// Adds the definitions a shape refers to that weren't added yet, making sure definitions come before the
+/// definitions that use them
+fn add_referenced_definitions(shape: &Shape, referenced_definitions: &mut Vec<Paint>) {
+    for paint in [&shape.fill, &shape.stroke].into_iter().flatten() {
+        let Some(id) = paint.definition_id() else {
+            continue;
+        };
+
+        if referenced_definitions
+            .iter()
+            .any(|definition| definition.definition_id() == Some(id))
+        {
+            continue;
+        }
+
+        if let Paint::Pattern(pattern) = paint {
+            for pattern_shape in &pattern.shapes {
+                add_referenced_definitions(pattern_shape, referenced_definitions);
+            }
+        }
+
+        referenced_definitions.push(paint.clone());
+    }
+}
+
+fn is_definitions(element: &Element) -> bool {
+    element.get_name() == "defs"
+}
+
+fn attributes(node: &dyn Node) -> BTreeMap<String, String> {
+    node.get_attributes()
+        .map(|attributes: &Attributes| {
+            attributes
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn attribute(node: &dyn Node, name: &str) -> Option<String> {
+    node.get_attributes()?
+        .get(name)
+        .map(|value| value.to_string())
+}
+
+fn children(node: &dyn Node) -> &[Box<dyn Node>] {
+    node.get_children()
+        .map(|children: &Children| children.as_slice())
+        .unwrap_or_default()
+}
+
+/// Moves a transform attribute into a list of transforms, unless it can't be represented by one
+fn take_transforms(attributes: &mut BTreeMap<String, String>, name: &str) -> Vec<Transform> {
+    match attributes
+        .get(name)
+        .and_then(|value| Transform::parse_list(value))
+    {
+        Some(transforms) => {
+            attributes.remove(name);
+            transforms
+        }
+        None => vec![],
+    }
+}
+
+/// Moves a fill or stroke attribute into a paint, resolving any gradient or pattern it refers to
+fn take_paint(
+    attributes: &mut BTreeMap<String, String>,
+    name: &str,
+    definitions: &Definitions,
+) -> Option<Paint> {
+    let value = attributes.get(name)?;
+
+    let paint = match value
+        .strip_prefix("url(#")
+        .and_then(|id| id.strip_suffix(')'))
+    {
+        Some(id) => {
+            let (_, definition, nested) = definitions
+                .iter()
+                .find(|(definition_id, _, _)| definition_id == id)
+                .expect("Referred to a definition that does not exist. This should never happen.");
+
+            definition_paint(*definition, *nested, definitions)
+        }
+        None => Paint::Color(parse_color(value)?),
+    };
+
+    attributes.remove(name);
+
+    Some(paint)
+}
+
+/// Parses a color in the format of `HSL::as_string()`
+fn parse_color(value: &str) -> Option<HSL> {
+    let mut parts = value.strip_prefix("hsla(")?.strip_suffix(')')?.split(',');
+
+    let hue = parts.next()?.parse::<i16>().ok()?;
+    let saturation = parts.next()?.strip_suffix('%')?.parse::<i8>().ok()?;
+    let lightness = parts.next()?.strip_suffix('%')?.parse::<i8>().ok()?;
+    let opacity = match parts.next()? {
+        "1" => 100,
+        opacity => opacity.strip_prefix("0.")?.parse::<i8>().ok()?,
+    };
+
+    let color = HSL::new(hue, saturation, lightness, opacity);
+
+    // Only accept it if nothing gets lost when writing it back
+    (parts.next().is_none() && color.as_string() == value).then_some(color)
+}
@@ -0,0 +1,190 @@
+use crate::hsl::*;
+use crate::layers::{Layer, LayerCategory};
+use crate::nft_generator::streams::RandomStreams;
+use crate::rng::Random;
+use crate::utils::IdAllocation;
+
+/// Describes how an NFT is composed: whether it gets a base color and which stages of layers are stacked on top of
+/// each other. `GeneratorConfig::default()` is the composition of the SVGenesis collection.
+#[derive(Clone, Debug)]
+pub struct GeneratorConfig {
+    /// The base color from which the colors of the layers are derived, if it's picked
+    pub base_color: Option<BaseColorConfig>,
+    /// The stages, in the order they are rolled and stacked
+    pub stages: Vec<Stage>,
+    /// The composition is rolled again until it has at least this many layers
+    pub min_layers: usize,
+    /// Whether all parts share a single random stream or roll from their own
+    pub random_streams: RandomStreams,
+    /// How the ids of gradients, filters and patterns are picked
+    pub id_allocation: IdAllocation,
+    /// Whether the SVG data is minified, see `renderer::render_minified()`
+    pub minify: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct BaseColorConfig {
+    /// The chance that the NFT gets a base color
+    pub chance: Chance,
+    /// The color modes to pick the base color from, with their weights
+    pub color_modes: Vec<(ColorMode, u8)>,
+}
+
+/// A stage picks layers from a single category
+#[derive(Clone, Debug)]
+pub struct Stage {
+    pub category: LayerCategory,
+    /// The chance that this stage is used
+    pub chance: Chance,
+    /// A condition that must hold for this stage to be used, which is checked after rolling the chance
+    pub condition: Condition,
+    /// The minimum and maximum number of layers to pick from the category
+    pub count: (u8, u8),
+    /// Stages that are only rolled when this stage is used
+    pub then: Vec<Stage>,
+}
+
+/// How likely something is to happen. The different kinds consume randomness differently, so switching between
+/// them changes the output of existing seeds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chance {
+    /// Always happens, without using any randomness
+    Always,
+    /// Happens with the provided chance out of 100, rolled as a u8
+    Percent(u8),
+    /// Happens with the provided chance out of 100, rolled as a u16
+    PercentU16(u16),
+    /// Happens half of the time
+    CoinFlip,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Condition {
+    Always,
+    HasBaseColor,
+    NoBaseColor,
+    /// A layer of the category was already picked
+    HasLayer(LayerCategory),
+}
+
+impl Default for GeneratorConfig {
+    /// The composition of the SVGenesis collection
+    fn default() -> Self {
+        GeneratorConfig {
+            base_color: Some(BaseColorConfig {
+                chance: Chance::Percent(30),
+                color_modes: vec![
+                    (ColorMode::Tone, 20),
+                    (ColorMode::Light, 30),
+                    (ColorMode::Vibrant, 50),
+                ],
+            }),
+            stages: vec![
+                Stage::new(LayerCategory::Background, Chance::Always),
+                Stage::new(LayerCategory::Frame, Chance::Percent(10))
+                    .with_condition(Condition::HasBaseColor),
+                Stage::new(LayerCategory::BigElement, Chance::CoinFlip).then(vec![Stage::new(
+                    LayerCategory::Overlay,
+                    Chance::PercentU16(5),
+                )]),
+                Stage::new(LayerCategory::SmallElement, Chance::CoinFlip),
+            ],
+            min_layers: 2,
+            random_streams: RandomStreams::Shared,
+            id_allocation: IdAllocation::Random,
+            minify: false,
+        }
+    }
+}
+
+impl Stage {
+    /// Creates a stage that picks a single layer from the category
+    pub fn new(category: LayerCategory, chance: Chance) -> Self {
+        Stage {
+            category,
+            chance,
+            condition: Condition::Always,
+            count: (1, 1),
+            then: vec![],
+        }
+    }
+
+    pub fn with_condition(mut self, condition: Condition) -> Self {
+        self.condition = condition;
+        self
+    }
+
+    pub fn with_count(mut self, min: u8, max: u8) -> Self {
+        assert!(
+            min <= max,
+            "The minimum count can't exceed the maximum count!"
+        );
+        self.count = (min, max);
+        self
+    }
+
+    pub fn then(mut self, stages: Vec<Stage>) -> Self {
+        self.then = stages;
+        self
+    }
+
+    /// Rolls the number of layers to pick. Doesn't use any randomness when the count is fixed.
+    pub fn roll_count(&self, random: &mut Random) -> u8 {
+        let (min, max) = self.count;
+        if min == max {
+            min
+        } else {
+            min + random.roll::<u8>(max - min + 1)
+        }
+    }
+}
+
+impl Chance {
+    pub fn roll(&self, random: &mut Random) -> bool {
+        match self {
+            Chance::Always => true,
+            Chance::Percent(percent) => random.roll::<u8>(100) < *percent,
+            Chance::PercentU16(percent) => random.roll::<u16>(100) < *percent,
+            Chance::CoinFlip => random.next_bool(),
+        }
+    }
+}
+
+impl Condition {
+    pub fn holds(&self, base_color: &Option<HSL>, layers: &[Box<dyn Layer>]) -> bool {
+        match self {
+            Condition::Always => true,
+            Condition::HasBaseColor => base_color.is_some(),
+            Condition::NoBaseColor => base_color.is_none(),
+            Condition::HasLayer(category) => {
+                layers.iter().any(|layer| layer.category() == *category)
+            }
+        }
+    }
+}
+
+impl BaseColorConfig {
+    /// Rolls whether there is a base color, and if so, picks it
+    pub fn roll(&self, random: &mut Random) -> Option<HSL> {
+        if !self.chance.roll(random) {
+            return None;
+        }
+
+        // Pick a color mode based on the weights
+        let total_weight: u8 = self.color_modes.iter().map(|(_, weight)| weight).sum();
+        let mut roll = random.roll::<u8>(total_weight);
+        let mut picked_color_mode = None;
+        for (color_mode, weight) in &self.color_modes {
+            if roll < *weight {
+                picked_color_mode = Some(*color_mode);
+                break;
+            }
+            roll -= weight;
+        }
+
+        let color_mode =
+            picked_color_mode.expect("Could not pick a color mode. This should never happen.");
+
+        Some(HSL::new_random(random, color_mode, 100))
+    }
+}
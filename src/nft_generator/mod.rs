@@ -0,0 +1,183 @@
+use crate::hsl::*;
+use crate::layers::{DefaultWeights, LayerWeights};
+use svg::node::element::Rectangle;
+use svg::Document;
+
+pub mod composition;
+pub mod config;
+pub mod optimizer;
+pub mod pipeline;
+pub mod renderer;
+pub mod streams;
+pub mod traits;
+pub mod v1;
+pub mod v2;
+
+pub use composition::Composition;
+pub use config::{BaseColorConfig, Chance, Condition, GeneratorConfig, Stage};
+pub use streams::RandomStreams;
+pub use traits::LayerTraits;
+
+/// The versions of the generator. Every minted NFT records the version it was generated with, so it can always be rendered again.
+///
+/// A frozen version must keep producing the exact same output for every seed. Changes to layers, weights or the order of rolls that would change its output go into a new version instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeneratorVersion {
+    V1,
+    /// Gives the base color, every stage and every layer their own random stream, definitions sequential ids and
+    /// minifies the SVG data
+    V2,
+}
+
+impl GeneratorVersion {
+    /// All known versions, oldest first
+    pub const ALL: [GeneratorVersion; 2] = [GeneratorVersion::V1, GeneratorVersion::V2];
+
+    /// The version that is used for new NFTs. Only frozen versions can be used for mints.
+    pub const CURRENT: GeneratorVersion = GeneratorVersion::V2;
+
+    pub const fn as_u32(self) -> u32 {
+        match self {
+            GeneratorVersion::V1 => 1,
+            GeneratorVersion::V2 => 2,
+        }
+    }
+
+    pub fn from_u32(version: u32) -> Option<GeneratorVersion> {
+        Self::ALL
+            .into_iter()
+            .find(|generator_version| generator_version.as_u32() == version)
+    }
+
+    /// Returns whether the output of this version is locked
+    pub fn is_frozen(self) -> bool {
+        match self {
+            GeneratorVersion::V1 => true,
+            GeneratorVersion::V2 => true,
+        }
+    }
+}
+
+/// The version of the generator that is used for new NFTs
+pub const GENERATOR_VERSION: u32 = GeneratorVersion::CURRENT.as_u32();
+
+/// Generates the SVG data and layer names for a seed using the current generator version and the default layer weights
+pub fn generate_nft_image_data(seed: &Vec<u8>) -> (String, Vec<String>) {
+    generate_nft_image_data_with_weights(seed, &DefaultWeights)
+}
+
+/// Generates the SVG data and layer names for a seed using the current generator version and the provided layer weights
+pub fn generate_nft_image_data_with_weights(
+    seed: &Vec<u8>,
+    weights: &dyn LayerWeights,
+) -> (String, Vec<String>) {
+    generate_nft_image_data_with_version(seed, GeneratorVersion::CURRENT, weights)
+}
+
+/// Generates the SVG data and layer names for a seed using a specific generator version and the provided layer weights
+pub fn generate_nft_image_data_with_version(
+    seed: &Vec<u8>,
+    generator_version: GeneratorVersion,
+    weights: &dyn LayerWeights,
+) -> (String, Vec<String>) {
+    match generator_version {
+        GeneratorVersion::V1 => v1::generate_nft_image_data(seed, weights),
+        GeneratorVersion::V2 => v2::generate_nft_image_data(seed, weights),
+    }
+}
+
+/// Composes the image for a seed using the current generator version and the provided layer weights, without rendering it to SVG
+pub fn compose_nft_image_with_weights(seed: &Vec<u8>, weights: &dyn LayerWeights) -> Composition {
+    compose_nft_image_with_version(seed, GeneratorVersion::CURRENT, weights)
+}
+
+/// Composes the image for a seed using a specific generator version and the provided layer weights, without rendering it to SVG
+pub fn compose_nft_image_with_version(
+    seed: &Vec<u8>,
+    generator_version: GeneratorVersion,
+    weights: &dyn LayerWeights,
+) -> Composition {
+    match generator_version {
+        GeneratorVersion::V1 => v1::compose_nft_image(seed, weights),
+        GeneratorVersion::V2 => v2::compose_nft_image(seed, weights),
+    }
+}
+
+/// Renders a composition to SVG data the way a specific generator version does
+pub fn render_composition(
+    composition: &Composition,
+    generator_version: GeneratorVersion,
+) -> String {
+    match generator_version {
+        GeneratorVersion::V1 => v1::render(composition),
+        GeneratorVersion::V2 => v2::render(composition),
+    }
+}
+
+/// Generates the SVG data and layer names for a seed using a custom composition, e.g. for a variant collection.
+///
+/// Unlike generator versions, custom configs aren't frozen, so they are not suitable for NFTs that are rendered on demand.
+pub fn generate_nft_image_data_with_config(
+    seed: &Vec<u8>,
+    config: &GeneratorConfig,
+    weights: &dyn LayerWeights,
+) -> (String, Vec<String>) {
+    pipeline::generate(seed, config, weights)
+}
+
+/// Composes the image for a seed using a custom composition, without rendering it to SVG
+pub fn compose_nft_image_with_config(
+    seed: &Vec<u8>,
+    config: &GeneratorConfig,
+    weights: &dyn LayerWeights,
+) -> Composition {
+    pipeline::compose(seed, config, weights)
+}
+
+/// Rebuilds the SVG data of an NFT from its seed and the generator version it was generated with, using the default layer weights.
+///
+/// Returns None if the generator version is unknown
+pub fn render_nft_image(seed: &Vec<u8>, generator_version: u32) -> Option<String> {
+    let generator_version = GeneratorVersion::from_u32(generator_version)?;
+
+    Some(generate_nft_image_data_with_version(seed, generator_version, &DefaultWeights).0)
+}
+
+/// Builds a canonical form of an image that only depends on what it looks like, so visually identical images can be detected.
+///
+/// It consists of the layer names and the SVG data, with the randomly generated gradient and filter ids replaced by their order of appearance.
+pub fn canonical_fingerprint(nft_image_data: &str, layers: &[String]) -> String {
+    // Collect the generated ids in the order they are defined
+    let mut ids: Vec<&str> = vec![];
+    for part in nft_image_data.split(" id=\"").skip(1) {
+        if let Some(end) = part.find('"') {
+            let id = &part[..end];
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+
+    // Replace the ids and their references with their index
+    let mut canonical_image_data = nft_image_data.to_string();
+    for (index, id) in ids.iter().enumerate() {
+        canonical_image_data = canonical_image_data
+            .replace(&format!(" id=\"{id}\""), &format!(" id=\"{index}\""))
+            .replace(&format!("url(#{id})"), &format!("url(#{index})"));
+    }
+
+    format!("{}\n{}", layers.join(","), canonical_image_data)
+}
+
+/// Generates the image that is shown while an NFT is waiting for its randomness
+pub fn generate_placeholder_image_data() -> String {
+    let background = Rectangle::new()
+        .set("width", "100%")
+        .set("height", "100%")
+        .set("fill", HSL::new(0, 0, 85, 100).as_string());
+
+    Document::new()
+        .set("viewBox", (0, 0, 1000, 1000))
+        .add(background)
+        .to_string()
+}
@@ -0,0 +1,101 @@
+use crate::nft_generator::composition::*;
+use std::collections::BTreeMap;
+
+/// Attributes that can be left out, because they are set to the value they default to
+const DEFAULT_ATTRIBUTES: [(&str, &str); 5] = [
+    ("x", "0"),
+    ("y", "0"),
+    ("opacity", "1"),
+    ("fill-opacity", "1"),
+    ("stroke-opacity", "1"),
+];
+
+/// Returns a composition that renders to the same pixels, but to less SVG data:
+/// - identical gradients and patterns are merged into the definition that is used first
+/// - gradients are no longer nested in their own `<defs>`
+/// - transforms that don't move anything, like `rotate(0, 500, 500)`, are dropped
+/// - attributes that are set to their default value are dropped
+/// - definitions that no shape refers to are dropped
+pub fn optimize(composition: &Composition) -> Composition {
+    let mut optimized_composition = composition.clone();
+
+    // The definitions that are kept, with their id left out so they can be compared to other definitions
+    let mut definitions: Vec<(Paint, String)> = vec![];
+    for layer in &mut optimized_composition.layers {
+        for shape in &mut layer.shapes {
+            optimize_shape(shape, &mut definitions);
+        }
+    }
+    optimized_composition.definitions = optimized_composition.referenced_definitions();
+
+    optimized_composition
+}
+
+fn optimize_shape(shape: &mut Shape, definitions: &mut Vec<(Paint, String)>) {
+    remove_default_attributes(&mut shape.attributes);
+    shape.transforms.retain(|transform| !is_no_op(transform));
+
+    for paint in [&mut shape.fill, &mut shape.stroke].into_iter().flatten() {
+        optimize_paint(paint, definitions);
+    }
+}
+
+fn optimize_paint(paint: &mut Paint, definitions: &mut Vec<(Paint, String)>) {
+    match paint {
+        Paint::Color(_) => return,
+        Paint::LinearGradient(gradient) | Paint::RadialGradient(gradient) => {
+            gradient.nested = false;
+            if gradient.angle.is_some_and(|angle| angle % 360 == 0) {
+                gradient.angle = None;
+            }
+        }
+        Paint::Pattern(pattern) => {
+            remove_default_attributes(&mut pattern.attributes);
+            pattern.transforms.retain(|transform| !is_no_op(transform));
+            for pattern_shape in &mut pattern.shapes {
+                optimize_shape(pattern_shape, definitions);
+            }
+        }
+    }
+
+    // Refer to an identical definition if there is one, otherwise keep this one
+    let mut definition = paint.clone();
+    set_definition_id(&mut definition, String::new());
+    match definitions.iter().find(|(kept, _)| *kept == definition) {
+        Some((_, id)) => set_definition_id(paint, id.clone()),
+        None => {
+            let id = paint
+                .definition_id()
+                .expect("A gradient or pattern always has an id.")
+                .to_string();
+            definitions.push((definition, id));
+        }
+    }
+}
+
+fn set_definition_id(paint: &mut Paint, id: String) {
+    match paint {
+        Paint::Color(_) => {}
+        Paint::LinearGradient(gradient) | Paint::RadialGradient(gradient) => gradient.id = id,
+        Paint::Pattern(pattern) => pattern.id = id,
+    }
+}
+
+fn remove_default_attributes(attributes: &mut BTreeMap<String, String>) {
+    for (name, default_value) in DEFAULT_ATTRIBUTES {
+        if attributes
+            .get(name)
+            .is_some_and(|value| value == default_value)
+        {
+            attributes.remove(name);
+        }
+    }
+}
+
+/// Whether a transform leaves everything where it is
+fn is_no_op(transform: &Transform) -> bool {
+    match transform {
+        Transform::Rotate { degrees, .. } => degrees % 360 == 0,
+        Transform::Translate { x, y } => *x == 0 && *y == 0,
+    }
+}
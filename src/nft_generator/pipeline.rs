@@ -0,0 +1,142 @@
+use crate::hsl::*;
+use crate::layers::*;
+use crate::nft_generator::composition::Composition;
+use crate::nft_generator::config::{GeneratorConfig, Stage};
+use crate::nft_generator::renderer;
+use crate::nft_generator::streams::RandomSource;
+use crate::utils::IdAllocator;
+
+/// Generates the SVG data and layer names for a seed by composing the layers as described by the config
+pub fn generate(
+    seed: &Vec<u8>,
+    config: &GeneratorConfig,
+    weights: &dyn LayerWeights,
+) -> (String, Vec<String>) {
+    let composition = compose(seed, config, weights);
+
+    (render(&composition, config), composition.layer_names())
+}
+
+/// Renders a composition to SVG data, minified if the config asks for it
+pub fn render(composition: &Composition, config: &GeneratorConfig) -> String {
+    match config.minify {
+        true => renderer::render_minified(composition).to_string(),
+        false => renderer::render(composition).to_string(),
+    }
+}
+
+/// Picks the layers for a seed as described by the config and resolves all of their random choices
+pub fn compose(
+    seed: &Vec<u8>,
+    config: &GeneratorConfig,
+    weights: &dyn LayerWeights,
+) -> Composition {
+    // Instantiate the randomness
+    let mut random_source = RandomSource::new(seed, config.random_streams);
+
+    // Set up our stack of layers
+    let mut layers: Vec<Box<dyn Layer>> = Vec::new();
+
+    // Optionally pick a base color
+    let base_color = config.base_color.as_ref().and_then(|base_color_config| {
+        random_source.with_stream("base-color", |random| base_color_config.roll(random))
+    });
+
+    // Make sure we have the minimum amount of layers
+    let mut attempt = 0;
+    while layers.len() < config.min_layers {
+        // Start clean
+        layers.clear();
+        let mut exclusions = vec![];
+
+        run_stages(
+            &config.stages,
+            &format!("attempt-{attempt}"),
+            &mut random_source,
+            &base_color,
+            weights,
+            &mut layers,
+            &mut exclusions,
+        );
+        attempt += 1;
+    }
+
+    // Generate the elements of all layers, in order, sharing the ids of the document
+    let mut ids = IdAllocator::new(config.id_allocation);
+    let mut layer_labels: Vec<String> = vec![];
+    let generated_layers = layers
+        .into_iter()
+        .map(|layer| {
+            let label = unique_label(format!("layer/{}", layer.id()), &mut layer_labels);
+            let elements = random_source.with_stream(&label, |random| {
+                layer.generate(random, &base_color, &mut ids)
+            });
+            (layer, elements)
+        })
+        .collect();
+
+    Composition::from_layers(base_color, generated_layers)
+}
+
+/// Rolls the stages in order and adds the picked layers and their exclusions to the stack
+fn run_stages(
+    stages: &[Stage],
+    parent_label: &str,
+    random_source: &mut RandomSource,
+    base_color: &Option<HSL>,
+    weights: &dyn LayerWeights,
+    layers: &mut Vec<Box<dyn Layer>>,
+    exclusions: &mut Vec<Exclusion>,
+) {
+    // Stages are labelled by their category, so adding a stage doesn't change the randomness of the others
+    let mut stage_labels: Vec<String> = vec![];
+
+    for stage in stages {
+        let label = unique_label(
+            format!("{parent_label}/{}", stage.category.as_str()),
+            &mut stage_labels,
+        );
+
+        let used = random_source.with_stream(&label, |random| {
+            // The chance is always rolled before checking the condition
+            if !stage.chance.roll(random) || !stage.condition.holds(base_color, layers) {
+                return false;
+            }
+
+            // Pick the layers and add any exclusions to the exclusions list
+            for _ in 0..stage.roll_count(random) {
+                if let Some(layer) = random_layer(random, stage.category, exclusions, weights) {
+                    exclusions.append(&mut layer.exclusions());
+                    layers.push(layer);
+                }
+            }
+
+            true
+        });
+
+        // Roll the stages that depend on this one
+        if used {
+            run_stages(
+                &stage.then,
+                &label,
+                random_source,
+                base_color,
+                weights,
+                layers,
+                exclusions,
+            );
+        }
+    }
+}
+
+/// Makes a label unique by numbering repeated labels, and remembers it
+fn unique_label(label: String, used_labels: &mut Vec<String>) -> String {
+    let occurrence = used_labels
+        .iter()
+        .filter(|used_label| used_label.starts_with(&format!("{label}#")))
+        .count();
+    let unique_label = format!("{label}#{occurrence}");
+    used_labels.push(unique_label.clone());
+
+    unique_label
+}
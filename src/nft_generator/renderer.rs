@@ -0,0 +1,152 @@
+use crate::hsl::HSL;
+use crate::nft_generator::composition::*;
+use crate::nft_generator::optimizer;
+use svg::node::element::{Definitions, Element};
+use svg::Document;
+use svg::Node;
+
+/// How colors are written in the SVG document
+#[derive(Clone, Copy)]
+enum ColorNotation {
+    /// `HSL::as_string()`, as in v1
+    Full,
+    /// `HSL::as_short_string()`
+    Short,
+}
+
+/// Renders a composition to an SVG document
+pub fn render(composition: &Composition) -> Document {
+    render_with_notation(composition, ColorNotation::Full)
+}
+
+/// Renders a composition to the smallest SVG document that looks the same: the composition is optimized first, see
+/// `optimizer::optimize()`, and colors use their short notation
+pub fn render_minified(composition: &Composition) -> Document {
+    render_with_notation(&optimizer::optimize(composition), ColorNotation::Short)
+}
+
+fn render_with_notation(composition: &Composition, notation: ColorNotation) -> Document {
+    // Set up the base Document
+    let mut document = Document::new().set("viewBox", (0, 0, 1000, 1000));
+
+    // Add the gradients and patterns of all layers in a single Definitions node at the beginning of the document
+    let mut defs = Definitions::new();
+    for definition in &composition.definitions {
+        match definition {
+            Paint::LinearGradient(gradient) | Paint::RadialGradient(gradient)
+                if gradient.nested =>
+            {
+                defs.append(Definitions::new().add(paint_definition_element(definition, notation)))
+            }
+            _ => defs.append(paint_definition_element(definition, notation)),
+        }
+    }
+
+    if defs
+        .get_children()
+        .is_some_and(|children| !children.is_empty())
+    {
+        document.append(defs)
+    };
+
+    // Add the shapes of all layers, from the bottom layer to the top layer
+    for shape in composition
+        .layers
+        .iter()
+        .flat_map(|layer| layer.shapes.iter())
+    {
+        document.append(shape_element(shape, notation));
+    }
+
+    document
+}
+
+fn shape_element(shape: &Shape, notation: ColorNotation) -> Element {
+    let mut element = Element::new(shape.kind.name());
+
+    for (name, value) in &shape.attributes {
+        element.assign(name.as_str(), value.as_str());
+    }
+
+    if !shape.transforms.is_empty() {
+        element.assign("transform", Transform::list_as_string(&shape.transforms));
+    }
+
+    if let Some(fill) = &shape.fill {
+        element.assign("fill", paint_string(fill, notation));
+    }
+
+    if let Some(stroke) = &shape.stroke {
+        element.assign("stroke", paint_string(stroke, notation));
+    }
+
+    element
+}
+
+/// The value of the `fill` or `stroke` attribute
+fn paint_string(paint: &Paint, notation: ColorNotation) -> String {
+    match paint {
+        Paint::Color(color) => color_string(color, notation),
+        _ => paint.as_string(),
+    }
+}
+
+fn color_string(color: &HSL, notation: ColorNotation) -> String {
+    match notation {
+        ColorNotation::Full => color.as_string(),
+        ColorNotation::Short => color.as_short_string(),
+    }
+}
+
+fn paint_definition_element(paint: &Paint, notation: ColorNotation) -> Element {
+    match paint {
+        Paint::Color(_) => panic!("A color has no definition. This should never happen."),
+        Paint::LinearGradient(gradient) => gradient_element("linearGradient", gradient, notation),
+        Paint::RadialGradient(gradient) => gradient_element("radialGradient", gradient, notation),
+        Paint::Pattern(pattern) => pattern_element(pattern, notation),
+    }
+}
+
+fn gradient_element(name: &str, gradient: &Gradient, notation: ColorNotation) -> Element {
+    let mut element = Element::new(name);
+    element.assign("id", gradient.id.as_str());
+
+    for (name, value) in &gradient.attributes {
+        element.assign(name.as_str(), value.as_str());
+    }
+
+    if let Some(angle) = gradient.angle {
+        element.assign("gradientTransform", Gradient::transform_as_string(angle));
+    }
+
+    for stop in &gradient.stops {
+        let mut stop_element = Element::new("stop");
+        stop_element.assign("offset", stop.offset.as_str());
+        stop_element.assign("stop-color", color_string(&stop.color, notation));
+        element.append(stop_element);
+    }
+
+    element
+}
+
+fn pattern_element(pattern: &Pattern, notation: ColorNotation) -> Element {
+    let mut element = Element::new("pattern");
+    element.assign("id", pattern.id.as_str());
+
+    for (name, value) in &pattern.attributes {
+        element.assign(name.as_str(), value.as_str());
+    }
+
+    if !pattern.transforms.is_empty() {
+        element.assign(
+            "patternTransform",
+            Transform::list_as_string(&pattern.transforms),
+        );
+    }
+
+    for shape in &pattern.shapes {
+        element.append(shape_element(shape, notation));
+    }
+
+    element
+}
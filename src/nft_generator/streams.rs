@@ -0,0 +1,53 @@
+use crate::rng::Random;
+use blake2::{digest::consts::U32, Blake2b, Digest};
+
+/// How the parts of a composition get their randomness
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RandomStreams {
+    /// Everything is rolled from a single stream, in order. Adding a roll anywhere changes everything rolled after it.
+    Shared,
+    /// The base color, every stage and every layer roll from their own Blake2b stream, derived from the full seed and a
+    /// label. Changing the rolls of one layer leaves the other layers unchanged.
+    PerLabel,
+}
+
+/// Hands out the randomness for the parts of a composition
+pub struct RandomSource<'a> {
+    seed: &'a Vec<u8>,
+    shared: Option<Random>,
+}
+
+impl<'a> RandomSource<'a> {
+    pub fn new(seed: &'a Vec<u8>, streams: RandomStreams) -> Self {
+        RandomSource {
+            seed,
+            shared: match streams {
+                RandomStreams::Shared => Some(Random::new(seed)),
+                RandomStreams::PerLabel => None,
+            },
+        }
+    }
+
+    /// Runs `roll` with the stream for the label. With shared streams, the label is ignored.
+    pub fn with_stream<T>(&mut self, label: &str, roll: impl FnOnce(&mut Random) -> T) -> T {
+        match &mut self.shared {
+            Some(random) => roll(random),
+            None => roll(&mut Random::from_derived_seed(derive_seed(
+                self.seed, label,
+            ))),
+        }
+    }
+}
+
+/// Derives the seed of the stream for a label from the full seed.
+///
+/// The label is prefixed with its length, so different splits of the same bytes into a label and a seed can't produce
+/// the same derived seed. It must never change once a frozen generator version uses it.
+pub fn derive_seed(seed: &[u8], label: &str) -> [u8; 32] {
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update((label.len() as u64).to_le_bytes());
+    hasher.update(label.as_bytes());
+    hasher.update(seed);
+
+    hasher.finalize().into()
+}
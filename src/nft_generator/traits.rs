@@ -0,0 +1,136 @@
+use crate::hsl::*;
+use crate::nft_generator::composition::*;
+
+/// The number of color names that are included in the traits of a layer
+const DOMINANT_COLORS: usize = 2;
+
+/// The traits of a single layer, in a form that can be shown by marketplaces
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LayerTraits {
+    /// The category of the layer, like "Small Element"
+    pub category: String,
+    /// The name of the layer, like "Star Shadow"
+    pub name: String,
+    /// The parameters the layer was generated with, like its fill, rotation or radius
+    pub parameters: Vec<(String, String)>,
+    /// The names of the colors that are used most in the layer, most used first
+    pub colors: Vec<String>,
+}
+
+impl Composition {
+    /// Returns the traits of every layer, from the bottom layer to the top layer
+    pub fn traits(&self) -> Vec<LayerTraits> {
+        self.layers.iter().map(ComposedLayer::traits).collect()
+    }
+}
+
+impl ComposedLayer {
+    pub fn traits(&self) -> LayerTraits {
+        let mut parameters: Vec<(String, String)> = vec![];
+
+        // The fill of the first painted shape, which is the main shape of most layers
+        if let Some(paint) = self
+            .shapes
+            .iter()
+            .find_map(|shape| shape.fill.as_ref().or(shape.stroke.as_ref()))
+        {
+            let fill = match paint {
+                Paint::Color(_) => "Solid",
+                Paint::LinearGradient(_) => "Gradient",
+                Paint::RadialGradient(_) => "Radial Gradient",
+                Paint::Pattern(_) => "Pattern",
+            };
+            parameters.push(("Fill".to_string(), fill.to_string()));
+        }
+
+        // The first rotation, as layers rotate all of their shapes together
+        if let Some(degrees) = self
+            .shapes
+            .iter()
+            .flat_map(|shape| shape.transforms.iter())
+            .find_map(|transform| match transform {
+                Transform::Rotate { degrees, .. } => Some(*degrees),
+                _ => None,
+            })
+        {
+            parameters.push(("Rotation".to_string(), degrees.to_string()));
+        }
+
+        // The size of the first circle or square
+        if let Some(radius) = self
+            .shapes
+            .iter()
+            .find(|shape| shape.kind == ShapeKind::Circle)
+            .and_then(|circle| circle.attributes.get("r"))
+        {
+            parameters.push(("Radius".to_string(), radius.to_string()));
+        }
+
+        if let Some(size) = self
+            .shapes
+            .iter()
+            .filter(|shape| shape.kind == ShapeKind::Rectangle)
+            .filter_map(|rectangle| {
+                let width = rectangle.attributes.get("width")?;
+                (rectangle.attributes.get("height") == Some(width) && !width.ends_with('%'))
+                    .then_some(width)
+            })
+            .next()
+        {
+            parameters.push(("Size".to_string(), size.to_string()));
+        }
+
+        LayerTraits {
+            category: self.category.display_name(),
+            name: self.name.clone(),
+            parameters,
+            colors: self.dominant_colors(),
+        }
+    }
+
+    /// Returns the names of the colors that are used most, most used first. Ties go to the color that is used first.
+    fn dominant_colors(&self) -> Vec<String> {
+        let mut colors: Vec<HSL> = vec![];
+        for shape in &self.shapes {
+            collect_colors(shape, &mut colors);
+        }
+
+        let mut color_counts: Vec<(String, usize)> = vec![];
+        for color in colors {
+            let name = color.color_name();
+            match color_counts
+                .iter_mut()
+                .find(|(counted, _)| *counted == name)
+            {
+                Some((_, count)) => *count += 1,
+                None => color_counts.push((name, 1)),
+            }
+        }
+
+        // A stable sort keeps the order of first use for equal counts
+        color_counts.sort_by(|(_, count1), (_, count2)| count2.cmp(count1));
+
+        color_counts
+            .into_iter()
+            .take(DOMINANT_COLORS)
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+/// Collects all colors a shape is painted with, including the colors of its gradients and patterns
+fn collect_colors(shape: &Shape, colors: &mut Vec<HSL>) {
+    for paint in [&shape.fill, &shape.stroke].into_iter().flatten() {
+        match paint {
+            Paint::Color(color) => colors.push(*color),
+            Paint::LinearGradient(gradient) | Paint::RadialGradient(gradient) => {
+                colors.extend(gradient.stops.iter().map(|stop| stop.color))
+            }
+            Paint::Pattern(pattern) => {
+                for pattern_shape in &pattern.shapes {
+                    collect_colors(pattern_shape, colors);
+                }
+            }
+        }
+    }
+}
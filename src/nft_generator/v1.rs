@@ -0,0 +1,24 @@
+use crate::layers::LayerWeights;
+use crate::nft_generator::composition::Composition;
+use crate::nft_generator::config::GeneratorConfig;
+use crate::nft_generator::pipeline;
+
+/// Generates the SVG data and layer names for a seed, using the default composition.
+///
+/// This version is frozen: anything that changes its output for an existing seed belongs in a new generator version.
+pub fn generate_nft_image_data(
+    seed: &Vec<u8>,
+    weights: &dyn LayerWeights,
+) -> (String, Vec<String>) {
+    pipeline::generate(seed, &GeneratorConfig::default(), weights)
+}
+
+/// Composes the image for a seed, using the default composition. Rendering it results in the output of `generate_nft_image_data()`.
+pub fn compose_nft_image(seed: &Vec<u8>, weights: &dyn LayerWeights) -> Composition {
+    pipeline::compose(seed, &GeneratorConfig::default(), weights)
+}
+
+/// Renders a composition to the SVG data of this version
+pub fn render(composition: &Composition) -> String {
+    pipeline::render(composition, &GeneratorConfig::default())
+}
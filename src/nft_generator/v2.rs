@@ -0,0 +1,38 @@
+use crate::layers::LayerWeights;
+use crate::nft_generator::composition::Composition;
+use crate::nft_generator::config::GeneratorConfig;
+use crate::nft_generator::pipeline;
+use crate::nft_generator::streams::RandomStreams;
+use crate::utils::IdAllocation;
+
+/// The default composition, but the base color, every stage and every layer roll from their own random stream,
+/// definitions get sequential ids and the SVG data is minified
+fn config() -> GeneratorConfig {
+    GeneratorConfig {
+        random_streams: RandomStreams::PerLabel,
+        id_allocation: IdAllocation::Sequential,
+        minify: true,
+        ..GeneratorConfig::default()
+    }
+}
+
+/// Generates the SVG data and layer names for a seed, using the default composition with a random stream per layer.
+///
+/// This version is frozen: anything that changes its output for an existing seed belongs in a new generator version.
+pub fn generate_nft_image_data(
+    seed: &Vec<u8>,
+    weights: &dyn LayerWeights,
+) -> (String, Vec<String>) {
+    pipeline::generate(seed, &config(), weights)
+}
+
+/// Composes the image for a seed, using the default composition with a random stream per layer. Rendering it results
+/// in the output of `generate_nft_image_data()`.
+pub fn compose_nft_image(seed: &Vec<u8>, weights: &dyn LayerWeights) -> Composition {
+    pipeline::compose(seed, &config(), weights)
+}
+
+/// Renders a composition to the SVG data of this version
+pub fn render(composition: &Composition) -> String {
+    pipeline::render(composition, &config())
+}
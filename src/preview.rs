@@ -0,0 +1,54 @@
+use crate::nft_generator::{canonical_fingerprint, generate_nft_image_data};
+use blake2::{digest::consts::U32, Blake2b, Digest};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// The image of a seed as it would be minted, for previews outside the ledger
+#[cfg_attr(feature = "wasm", wasm_bindgen(getter_with_clone))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Preview {
+    /// The SVG data
+    pub svg: String,
+    /// The layer names
+    pub layers: Vec<String>,
+    /// The hash of the image's fingerprint, which the component uses to detect duplicate images
+    pub hash: String,
+}
+
+/// Generates the image for a hex-encoded seed like `generate_nft_image_data` does
+pub fn preview_seed(seed_hex: &str) -> Result<Preview, String> {
+    let seed = decode_seed(seed_hex)?;
+    let (svg, layers) = generate_nft_image_data(&seed);
+    let hash = fingerprint_hash(&svg, &layers);
+
+    Ok(Preview { svg, layers, hash })
+}
+
+/// Decodes a hex-encoded seed, checking that it isn't empty and its length is a multiple of 4 like the component does
+pub fn decode_seed(seed_hex: &str) -> Result<Vec<u8>, String> {
+    let seed = hex::decode(seed_hex).map_err(|error| format!("Invalid hex: {error}"))?;
+    if seed.is_empty() {
+        return Err("Seed can't be empty!".to_string());
+    }
+    if seed.len() % 4 != 0 {
+        return Err("Seed length must be a multiple of 4!".to_string());
+    }
+
+    Ok(seed)
+}
+
+/// Returns the hex-encoded hash of the fingerprint of an image. Scrypto's `hash` is Blake2b-256, so this is the same
+/// hash the component stores, without depending on scrypto.
+pub fn fingerprint_hash(nft_image_data: &str, layers: &[String]) -> String {
+    hex::encode(Blake2b::<U32>::digest(
+        canonical_fingerprint(nft_image_data, layers).as_bytes(),
+    ))
+}
+
+/// The JavaScript entry point: `preview(seedHex)` returns an object with `svg`, `layers` and `hash`, or throws if the
+/// seed is invalid
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(js_name = preview)]
+pub fn preview_wasm(seed_hex: &str) -> Result<Preview, JsError> {
+    preview_seed(seed_hex).map_err(|error| JsError::new(&error))
+}
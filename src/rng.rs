@@ -0,0 +1,110 @@
+use blake2::{Blake2b512, Digest};
+
+/// The randomness that the base color, the stages and the layers roll from.
+///
+/// Generator v1 rolls from .Random, seeded with the seed of the NFT. Streams that are derived from the seed and a label
+/// roll from Blake2b in counter mode instead, so every roll depends on the full seed and the label.
+pub struct Random(Source);
+
+enum Source {
+    Dot(random::Random),
+    Blake2b(Blake2bStream),
+}
+
+impl Random {
+    /// Rolls from .Random, seeded with the provided seed. The seed length must be a multiple of 4.
+    pub fn new(seed: &Vec<u8>) -> Self {
+        Random(Source::Dot(random::Random::new(seed)))
+    }
+
+    /// Rolls from Blake2b in counter mode, keyed with a seed derived by `streams::derive_seed()`
+    pub fn from_derived_seed(derived_seed: [u8; 32]) -> Self {
+        Random(Source::Blake2b(Blake2bStream {
+            key: derived_seed,
+            counter: 0,
+            block: vec![],
+        }))
+    }
+
+    /// Returns a random number from 0 up to, but not including, max
+    pub fn roll<T: RandomNumber>(&mut self, max: T) -> T {
+        match &mut self.0 {
+            Source::Dot(random) => T::dot_roll(random, max),
+            Source::Blake2b(stream) => T::from_u64(stream.next_u64() % max.into_u64()),
+        }
+    }
+
+    /// Returns a random number from min up to, but not including, max
+    pub fn in_range<T: RandomNumber>(&mut self, min: T, max: T) -> T {
+        match &mut self.0 {
+            Source::Dot(random) => T::dot_in_range(random, min, max),
+            Source::Blake2b(stream) => {
+                T::from_u64(min.into_u64() + stream.next_u64() % (max.into_u64() - min.into_u64()))
+            }
+        }
+    }
+
+    pub fn next_bool(&mut self) -> bool {
+        match &mut self.0 {
+            Source::Dot(random) => random.next_bool(),
+            Source::Blake2b(stream) => stream.next_u64() & 1 == 1,
+        }
+    }
+}
+
+/// Hashes the key and a counter into blocks of 64 bytes and reads them 8 bytes at a time
+struct Blake2bStream {
+    key: [u8; 32],
+    counter: u64,
+    block: Vec<u8>,
+}
+
+impl Blake2bStream {
+    fn next_u64(&mut self) -> u64 {
+        if self.block.is_empty() {
+            let mut hasher = Blake2b512::new();
+            hasher.update(self.key);
+            hasher.update(self.counter.to_le_bytes());
+            self.block = hasher.finalize().to_vec();
+            self.counter += 1;
+        }
+
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.block[..8]);
+        self.block.drain(..8);
+
+        u64::from_le_bytes(bytes)
+    }
+}
+
+/// The number types that can be rolled
+pub trait RandomNumber: Copy {
+    fn from_u64(value: u64) -> Self;
+    fn into_u64(self) -> u64;
+    fn dot_roll(random: &mut random::Random, max: Self) -> Self;
+    fn dot_in_range(random: &mut random::Random, min: Self, max: Self) -> Self;
+}
+
+macro_rules! implement_random_number {
+    ($($primitive:ty),*) => {
+        $(impl RandomNumber for $primitive {
+            fn from_u64(value: u64) -> Self {
+                value as $primitive
+            }
+
+            fn into_u64(self) -> u64 {
+                self as u64
+            }
+
+            fn dot_roll(random: &mut random::Random, max: Self) -> Self {
+                random.roll::<$primitive>(max)
+            }
+
+            fn dot_in_range(random: &mut random::Random, min: Self, max: Self) -> Self {
+                random.in_range::<$primitive>(min, max)
+            }
+        })*
+    };
+}
+
+implement_random_number!(u8, u16, u32, usize);
@@ -0,0 +1,74 @@
+use miniz_oxide::deflate::{compress_to_vec, CompressionLevel};
+use miniz_oxide::inflate::decompress_to_vec_with_limit;
+#[cfg(feature = "blueprint")]
+use scrypto::prelude::*;
+
+/// The maximum size of decompressed SVG data, so a corrupt `Deflate` can't make a reader allocate without bounds
+pub const MAX_SVG_DATA_SIZE: usize = 1024 * 1024;
+
+/// The SVG data of an NFT as it's stored in its `svg_data`
+#[cfg_attr(feature = "blueprint", derive(ScryptoSbor))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SVGData {
+    /// No SVG data is stored, because the image is pending or rendered from the seed in compact storage mode
+    Empty,
+    /// The UTF-8 bytes of the SVG
+    Raw(Vec<u8>),
+    /// The UTF-8 bytes of the SVG, compressed with raw deflate (RFC 1951)
+    Deflate(Vec<u8>),
+}
+
+impl SVGData {
+    /// Encodes SVG data in the smallest form, preferring `Raw` when compressing doesn't make it smaller
+    pub fn encode(nft_image_data: &str) -> Self {
+        let compressed = compress_to_vec(
+            nft_image_data.as_bytes(),
+            CompressionLevel::DefaultLevel as u8,
+        );
+
+        if compressed.len() < nft_image_data.len() {
+            SVGData::Deflate(compressed)
+        } else {
+            SVGData::Raw(nft_image_data.as_bytes().to_vec())
+        }
+    }
+
+    /// Decodes the SVG data
+    ///
+    /// Returns `None` if it's empty or can't be decoded
+    pub fn decode(&self) -> Option<String> {
+        match self {
+            SVGData::Empty => None,
+            SVGData::Raw(bytes) => String::from_utf8(bytes.clone()).ok(),
+            SVGData::Deflate(compressed) => {
+                let bytes = decompress_to_vec_with_limit(compressed, MAX_SVG_DATA_SIZE).ok()?;
+                String::from_utf8(bytes).ok()
+            }
+        }
+    }
+
+    /// Migrates the hex-encoded `svg_data` string that NFTs were stored with before
+    ///
+    /// Returns `None` if it isn't valid hex-encoded SVG data
+    pub fn from_hex(hex_svg_data: &str) -> Option<Self> {
+        if hex_svg_data.is_empty() {
+            return Some(SVGData::Empty);
+        }
+
+        let nft_image_data = String::from_utf8(hex::decode(hex_svg_data).ok()?).ok()?;
+
+        Some(SVGData::encode(&nft_image_data))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == SVGData::Empty
+    }
+
+    /// The number of bytes that are stored
+    pub fn len(&self) -> usize {
+        match self {
+            SVGData::Empty => 0,
+            SVGData::Raw(bytes) | SVGData::Deflate(bytes) => bytes.len(),
+        }
+    }
+}
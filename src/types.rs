@@ -1,10 +1,102 @@
+use crate::nft_generator::LayerTraits;
+use crate::svg_data::SVGData;
 use scrypto::prelude::*;
 
 #[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
 pub struct SVGenesisNFT {
+    #[mutable]
     pub key_image_url: Url,
     pub name: String,
+    #[mutable]
     pub seed_lossy: String,
+    #[mutable]
     pub layers: Vec<String>,
-    pub svg_data: String,
+    #[mutable]
+    pub traits: Vec<SVGenesisTrait>,
+    /// The SVG data in full storage mode, see `SVGData`
+    #[mutable]
+    pub svg_data: SVGData,
+    #[mutable]
+    pub seed: Vec<u8>,
+    #[mutable]
+    pub generator_version: u32,
+    /// The rarity score as it was last written by `update_rarity_score`
+    #[mutable]
+    pub rarity_score: Option<Decimal>,
+}
+
+/// Describes a single layer of an NFT, so marketplaces can show it as a trait
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct SVGenesisTrait {
+    /// The category of the layer, like "Small Element"
+    pub category: String,
+    /// The name of the layer, like "Star Shadow"
+    pub name: String,
+    /// The parameters the layer was generated with, like its fill, rotation or radius
+    pub parameters: Vec<(String, String)>,
+    /// The names of the colors that are used most in the layer, most used first
+    pub colors: Vec<String>,
+}
+
+impl SVGenesisTrait {
+    /// The keys this trait is counted under: one for the layer and one for every parameter of the layer
+    pub fn keys(&self) -> Vec<String> {
+        let layer_key = format!("{}/{}", self.category, self.name);
+
+        let mut keys = vec![layer_key.clone()];
+        keys.extend(
+            self.parameters
+                .iter()
+                .map(|(name, value)| format!("{layer_key}/{name}: {value}")),
+        );
+
+        keys
+    }
+}
+
+impl From<LayerTraits> for SVGenesisTrait {
+    fn from(layer_traits: LayerTraits) -> Self {
+        SVGenesisTrait {
+            category: layer_traits.category,
+            name: layer_traits.name,
+            parameters: layer_traits.parameters,
+            colors: layer_traits.colors,
+        }
+    }
+}
+
+/// How common a trait is among the minted NFTs
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct TraitFrequency {
+    /// The key of the trait, like "Small Element/Star" or "Small Element/Star/Fill: Gradient"
+    pub trait_key: String,
+    /// The number of minted NFTs with this trait
+    pub count: u64,
+    /// The share of minted NFTs with this trait, between 0 and 1
+    pub frequency: Decimal,
+}
+
+/// The rarity of an NFT
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct Rarity {
+    pub frequencies: Vec<TraitFrequency>,
+    /// The sum of the inverse frequencies of all traits, so rarer NFTs have a higher score
+    pub score: Decimal,
+}
+
+#[derive(ScryptoSbor, ManifestSbor, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MintPhase {
+    Closed,
+    Allowlist,
+    Public,
+    SoldOut,
+}
+
+/// Where the image of an NFT is kept
+#[derive(ScryptoSbor, ManifestSbor, Clone, Debug, PartialEq, Eq)]
+pub enum StorageMode {
+    /// The SVG is stored on-ledger, both in the key_image_url and the svg_data
+    Full,
+    /// Only the seed and generator version are stored. The key_image_url points to `image_base_url` followed by the NFT id, and the SVG can be rebuilt with `render`.
+    Compact { image_base_url: String },
 }
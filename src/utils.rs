@@ -1,14 +1,77 @@
-use std::any::TypeId;
-
-use crate::{hsl::*, layers::Layer};
-use random::Random;
+use crate::rng::Random;
+use crate::{
+    hsl::*,
+    layers::{Exclusion, Layer, LayerWeights},
+};
+use std::fmt::Display;
 use svg::node::element::{
     Definitions, Filter, FilterEffectDropShadow, LinearGradient, RadialGradient, Stop,
 };
+use svg::node::Value;
+
+/// The kind of definition an id is allocated for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DefinitionKind {
+    Gradient,
+    Filter,
+    Pattern,
+}
+
+/// How the ids of definitions like gradients are picked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdAllocation {
+    /// A random number, like `gr12345`. Every id consumes randomness and two ids in a document can collide.
+    Random,
+    /// A counter per document, like `g0` and `f1`
+    Sequential,
+}
+
+/// Hands out the ids for the definitions of a single document
+pub struct IdAllocator {
+    allocation: IdAllocation,
+    next_id: u32,
+}
+
+impl IdAllocator {
+    pub fn new(allocation: IdAllocation) -> Self {
+        IdAllocator {
+            allocation,
+            next_id: 0,
+        }
+    }
+
+    /// Returns a new id for a definition
+    pub fn allocate(&mut self, random: &mut Random, kind: DefinitionKind) -> String {
+        match self.allocation {
+            IdAllocation::Random => {
+                let prefix = match kind {
+                    DefinitionKind::Gradient => "gr",
+                    DefinitionKind::Filter => "f",
+                    DefinitionKind::Pattern => "pat",
+                };
+
+                format!("{prefix}{}", random.in_range::<u16>(0, 65535))
+            }
+            IdAllocation::Sequential => {
+                let prefix = match kind {
+                    DefinitionKind::Gradient => "g",
+                    DefinitionKind::Filter => "f",
+                    DefinitionKind::Pattern => "p",
+                };
+
+                let id = format!("{prefix}{}", self.next_id);
+                self.next_id += 1;
+
+                id
+            }
+        }
+    }
+}
 
 /// Generates a gradient using randomness
 pub fn random_gradient_definition(
     random: &mut Random,
+    ids: &mut IdAllocator,
     rotation: Option<u16>,
     color_mode: ColorMode,
     opacity: i8,
@@ -38,18 +101,19 @@ pub fn random_gradient_definition(
     };
 
     // Generate the gradient with the random colors and return the result
-    gradient_definition(random, rotation, random_color1, random_color2)
+    gradient_definition(random, ids, rotation, random_color1, random_color2)
 }
 
 /// Generates a gradient using color input
 pub fn gradient_definition(
     random: &mut Random,
+    ids: &mut IdAllocator,
     rotation: Option<u16>,
     color1: HSL,
     color2: HSL,
 ) -> (Definitions, String) {
     // Set up the gradient
-    let gradient_name = format!("gr{}", random.in_range::<u16>(0, 65535));
+    let gradient_name = ids.allocate(random, DefinitionKind::Gradient);
     let mut gradient = LinearGradient::new()
         .set("id", gradient_name.clone())
         .add(
@@ -78,12 +142,13 @@ pub fn gradient_definition(
 /// Generates a radial gradient using color input
 pub fn radial_gradient_definition(
     random: &mut Random,
+    ids: &mut IdAllocator,
     rotation: Option<u16>,
     color1: HSL,
     color2: HSL,
 ) -> (Definitions, String) {
     // Set up the radial gradient
-    let gradient_name = format!("gr{}", random.in_range::<u16>(0, 65535));
+    let gradient_name = ids.allocate(random, DefinitionKind::Gradient);
     let mut gradient = RadialGradient::new()
         .set("id", gradient_name.clone())
         .add(
@@ -112,6 +177,7 @@ pub fn radial_gradient_definition(
 /// Generates a drop-shadow definition
 pub fn drop_shadow_definition(
     random: &mut Random,
+    ids: &mut IdAllocator,
     dx: i8,
     dy: i8,
     std_deviation: i8,
@@ -119,7 +185,7 @@ pub fn drop_shadow_definition(
     flood_opacity: u8, // 0-100
 ) -> (Definitions, String) {
     // Create filter
-    let filter_name = format!("f{}", random.in_range::<u16>(0, 65535));
+    let filter_name = ids.allocate(random, DefinitionKind::Filter);
     let mut filter = Filter::new()
         .set("x", "-50%")
         .set("y", "-50%")
@@ -172,13 +238,113 @@ pub fn pick_random_layer(
     None
 }
 
+/// Replaces the default weights of the layers with the provided weights and filters out any disabled layers
+pub fn apply_weights(
+    available_layers: Vec<(Box<dyn Layer>, u32)>,
+    weights: &dyn LayerWeights,
+) -> Vec<(Box<dyn Layer>, u32)> {
+    available_layers
+        .into_iter()
+        .map(|(layer, default_weight)| {
+            let weight = weights.weight(layer.id(), default_weight);
+            (layer, weight)
+        })
+        .filter(|(_, weight)| *weight > 0)
+        .collect()
+}
+
 /// Generates a vector of allowed layers based on the provided exclusions
 pub fn exclude_layers(
     available_layers: Vec<(Box<dyn Layer>, u32)>,
-    exclusions: &[TypeId],
+    exclusions: &[Exclusion],
 ) -> Vec<(Box<dyn Layer>, u32)> {
     available_layers
         .into_iter()
-        .filter(|(layer, _)| !exclusions.contains(&layer.layer_type()))
+        .filter(|(layer, _)| {
+            !exclusions
+                .iter()
+                .any(|exclusion| exclusion.excludes(layer.as_ref()))
+        })
         .collect()
 }
+
+/// Path data for the `d` attribute of a path. It's written like svg's `Data`, which stores its numbers as floats, and
+/// floats aren't allowed in a blueprint.
+#[derive(Clone, Debug, Default)]
+pub struct Data(Vec<String>);
+
+impl Data {
+    pub fn new() -> Self {
+        Data(vec![])
+    }
+
+    pub fn move_to(self, parameters: impl Into<Parameters>) -> Self {
+        self.add("M", parameters.into())
+    }
+
+    pub fn line_to(self, parameters: impl Into<Parameters>) -> Self {
+        self.add("L", parameters.into())
+    }
+
+    pub fn horizontal_line_to(self, parameters: impl Into<Parameters>) -> Self {
+        self.add("H", parameters.into())
+    }
+
+    pub fn vertical_line_to(self, parameters: impl Into<Parameters>) -> Self {
+        self.add("V", parameters.into())
+    }
+
+    pub fn elliptical_arc_to(self, parameters: impl Into<Parameters>) -> Self {
+        self.add("A", parameters.into())
+    }
+
+    pub fn close(self) -> Self {
+        self.add("z", Parameters(vec![]))
+    }
+
+    fn add(mut self, command: &str, Parameters(parameters): Parameters) -> Self {
+        self.0.push(format!("{command}{}", parameters.join(",")));
+        self
+    }
+}
+
+impl From<Data> for Value {
+    fn from(Data(commands): Data) -> Self {
+        commands.join(" ").into()
+    }
+}
+
+/// The numbers of a path command
+pub struct Parameters(Vec<String>);
+
+/// A number that can be used in path data
+pub trait Number: Display {}
+
+macro_rules! implement_number {
+    ($($primitive:ty),*) => {
+        $(impl Number for $primitive {})*
+    };
+}
+
+implement_number!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl<T: Number> From<T> for Parameters {
+    fn from(value: T) -> Self {
+        Parameters(vec![value.to_string()])
+    }
+}
+
+macro_rules! implement_parameters {
+    ($(($($t:ident: $n:tt),*)),*) => {
+        $(impl<$($t: Number),*> From<($($t),*)> for Parameters {
+            fn from(values: ($($t),*)) -> Self {
+                Parameters(vec![$(values.$n.to_string()),*])
+            }
+        })*
+    };
+}
+
+implement_parameters!(
+    (T0: 0, T1: 1),
+    (T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6)
+);
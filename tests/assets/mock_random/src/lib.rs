@@ -0,0 +1,54 @@
+use scrypto::prelude::*;
+
+// A stand-in for the .Random RandomComponent, so the randomness callbacks can be tested locally.
+// Instead of a watcher delivering randomness, the test calls `execute` or `fail` to trigger the callbacks.
+
+#[blueprint]
+mod mock_random {
+    struct MockRandom {
+        requests: Vec<(ComponentAddress, String, String, u32)>,
+    }
+
+    impl MockRandom {
+        pub fn instantiate() -> Global<MockRandom> {
+            Self { requests: vec![] }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        /// Registers a request for randomness, using the same interface as the RandomComponent
+        ///
+        /// Returns the request id
+        pub fn request_random(
+            &mut self,
+            address: ComponentAddress,
+            method_name: String,
+            on_error: String,
+            key: u32,
+            badge_opt: Option<FungibleBucket>,
+            _expected_fee: u8,
+        ) -> u32 {
+            assert!(badge_opt.is_none(), "The mock does not support badges!");
+
+            self.requests.push((address, method_name, on_error, key));
+
+            self.requests.len() as u32
+        }
+
+        /// Calls back all pending requests with the provided randomness
+        pub fn execute(&mut self, random_seed: Vec<u8>) {
+            for (address, method_name, _, key) in std::mem::take(&mut self.requests) {
+                Global::<AnyComponent>::from(address)
+                    .call_raw::<()>(&method_name, scrypto_args!(key, random_seed.clone()));
+            }
+        }
+
+        /// Calls the error callback for all pending requests
+        pub fn fail(&mut self) {
+            for (address, _, on_error, key) in std::mem::take(&mut self.requests) {
+                Global::<AnyComponent>::from(address).call_raw::<()>(&on_error, scrypto_args!(key));
+            }
+        }
+    }
+}
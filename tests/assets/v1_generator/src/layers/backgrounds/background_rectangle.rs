@@ -0,0 +1,63 @@
+use crate::hsl::*;
+use crate::layers::Layer;
+use crate::utils::*;
+use random::Random;
+use svg::node::element::{Element, Rectangle};
+
+pub struct BackgroundRectangle;
+
+impl Layer for BackgroundRectangle {
+    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+        // Generate the rectangle that will be our background
+        let mut rectangle = Rectangle::new().set("width", "100%").set("height", "100%");
+
+        // Set the fill, which can be either solid or gradient
+        if random.roll::<u8>(100) < 80 {
+            // Pick a solid color
+            let color = if base_color.is_some() {
+                base_color.unwrap().as_string() // Since it's a solid background, we just use the base color as the background
+            } else {
+                // Pick a random color
+                let roll = random.roll::<u8>(100);
+                let color_mode = if roll < 15 {
+                    ColorMode::Tone
+                } else if roll < 50 {
+                    ColorMode::Light
+                } else {
+                    ColorMode::Vibrant
+                };
+
+                HSL::new_random(random, color_mode, 100).as_string()
+            };
+
+            rectangle = rectangle.set("fill", color);
+
+            vec![rectangle.into()]
+        } else {
+            // Get a gradient definition
+            let (gradient, gradient_name) = if base_color.is_some() {
+                // We have a base color, so we derive something similar
+                let color1 = base_color.unwrap().derive_similar_color(random);
+                let color2 = base_color.unwrap().derive_similar_color(random);
+
+                gradient_definition(random, Some(45), color1, color2)
+            } else {
+                // Pick a random color
+                let roll = random.roll::<u8>(100);
+                let color_mode = if roll < 15 {
+                    ColorMode::Tone
+                } else if roll < 50 {
+                    ColorMode::Light
+                } else {
+                    ColorMode::Vibrant
+                };
+
+                random_gradient_definition(random, Some(45), color_mode, 100)
+            };
+
+            rectangle = rectangle.set("fill", format!("url(#{gradient_name})",));
+
+            vec![gradient.into(), rectangle.into()]
+        }
+    }
+}
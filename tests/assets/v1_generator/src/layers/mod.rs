@@ -0,0 +1,13 @@
+pub mod backgrounds;
+pub mod big_elements;
+pub mod frames;
+pub mod layer;
+pub mod overlays;
+pub mod small_elements;
+
+pub use backgrounds::*;
+pub use big_elements::*;
+pub use frames::*;
+pub use layer::Layer;
+pub use overlays::*;
+pub use small_elements::*;
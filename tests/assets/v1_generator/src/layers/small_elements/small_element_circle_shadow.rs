@@ -0,0 +1,99 @@
+use crate::hsl::*;
+use crate::{layers::Layer, utils::*};
+use random::Random;
+use svg::node::element::{Circle, Element};
+
+pub struct SmallElementCircleShadow;
+
+impl Layer for SmallElementCircleShadow {
+    fn generate(&self, random: &mut Random, base_color: &Option<HSL>) -> Vec<Element> {
+        let random_radius = random.in_range::<u16>(35, 100) * 2; // Always an even number
+
+        let mut circle = Circle::new()
+            .set("cx", 500)
+            .set("cy", 500)
+            .set("r", random_radius)
+            .set("transform", "translate(-5, -5)");
+
+        let mut circle_shadow = circle.clone().set("transform", "translate(5, 5)");
+
+        // Initalialize the elements vector
+        let mut elements: Vec<Element> = vec![];
+
+        // Set the fill, which can be either solid or gradient, with a higher chance of solid than gradient
+        if random.roll::<u8>(100) < 85 {
+            let color = if base_color.is_some() {
+                // Use the base color and derive something similar
+                base_color.unwrap().derive_similar_color(random)
+            } else {
+                // Pick a random color
+                let roll = random.roll::<u8>(100);
+                let color_mode = if roll < 30 {
+                    ColorMode::Light
+                } else {
+                    ColorMode::Vibrant
+                };
+
+                HSL::new_random(random, color_mode, 100)
+            };
+
+            circle = circle.set("fill", color.as_string());
+            circle_shadow = circle_shadow.set(
+                "fill",
+                HSL {
+                    lightness: color.lightness - 10,
+                    ..color
+                }
+                .as_string(),
+            );
+
+            elements.extend(vec![circle_shadow.into(), circle.into()]);
+        } else {
+            // Get a gradient definition
+            let ((gradient, gradient_name), shadow_color) = if base_color.is_some() {
+                // We have a base color, so we derive something similar
+                let color1 = base_color.unwrap().derive_similar_color(random);
+                let color2 = base_color.unwrap().derive_similar_color(random);
+                let color3 = HSL {
+                    lightness: color2.lightness - 10,
+                    ..color2
+                }
+                .as_string();
+
+                (
+                    gradient_definition(random, Some(45), color1, color2),
+                    color3,
+                )
+            } else {
+                // Randomize the color mode, but prefer vibrant
+                let roll = random.roll::<u8>(100);
+                let color_mode = if roll < 30 {
+                    ColorMode::Light
+                } else {
+                    ColorMode::Vibrant
+                };
+
+                let color1 = HSL::new_random(random, color_mode, 100);
+                let color2 = HSL::new_random(random, color_mode, 100);
+                let color3 = HSL {
+                    lightness: color2.lightness - 10,
+                    ..color2
+                }
+                .as_string();
+
+                (
+                    gradient_definition(random, Some(45), color1, color2),
+                    color3,
+                )
+            };
+
+            circle = circle.set("fill", format!("url(#{gradient_name})",));
+            circle_shadow = circle_shadow.set("fill", shadow_color);
+
+            elements.extend(vec![gradient.into(), circle_shadow.into(), circle.into()]);
+        }
+
+        // Return vector of elements
+        elements
+    }
+}
@@ -0,0 +1,7 @@
+// The generator as it was when generator v1 was frozen, copied verbatim without the blueprint, so the tests can check
+// that v1 still produces the same images for any seed. Never change these files.
+
+pub mod hsl;
+pub mod layers;
+pub mod nft_generator;
+pub mod utils;
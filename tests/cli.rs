@@ -0,0 +1,87 @@
+use std::fs;
+use std::process::Command;
+
+#[test]
+fn cli_renders_batch_to_json_lines() {
+    // Arrange
+    let out_dir = std::env::temp_dir().join(format!("svgenesis-cli-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&out_dir);
+    let seeds: Vec<String> = (0..8u8).map(|index| hex::encode([index; 128])).collect();
+
+    // Act
+    let output = Command::new(env!("CARGO_BIN_EXE_svgenesis"))
+        .arg("--out")
+        .arg(&out_dir)
+        .arg("--png-size")
+        .arg("100")
+        .args(&seeds)
+        .output()
+        .unwrap();
+
+    // Assert
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let lines: Vec<serde_json::Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), seeds.len());
+
+    for (line, seed) in lines.iter().zip(&seeds) {
+        assert_eq!(line["seed"], *seed);
+        assert_eq!(
+            line["generator_version"],
+            svgenesis::nft_generator::GENERATOR_VERSION
+        );
+        assert!(line["layers"].as_array().unwrap().len() >= 2);
+
+        let hash = line["hash"].as_str().unwrap();
+        let svg_path = line["svg"].as_str().unwrap();
+        let png_path = line["png"].as_str().unwrap();
+        assert!(svg_path.ends_with(&format!("{hash}.svg")));
+        assert!(fs::read_to_string(svg_path).unwrap().starts_with("<svg"));
+        assert!(fs::metadata(png_path).unwrap().len() > 0);
+    }
+
+    // Every image got its own files
+    assert_eq!(fs::read_dir(&out_dir).unwrap().count(), 2 * seeds.len());
+
+    fs::remove_dir_all(&out_dir).unwrap();
+}
+
+#[test]
+fn cli_rejects_invalid_seeds_and_keeps_going() {
+    // Arrange
+    let out_dir =
+        std::env::temp_dir().join(format!("svgenesis-cli-invalid-{}", std::process::id()));
+    let seed = hex::encode([1u8; 128]);
+
+    // Act
+    let output = Command::new(env!("CARGO_BIN_EXE_svgenesis"))
+        .arg("--out")
+        .arg(&out_dir)
+        .arg("--no-png")
+        .args(["abc", &seed])
+        .output()
+        .unwrap();
+
+    // Assert
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("abc: Invalid hex"));
+
+    let lines: Vec<serde_json::Value> = String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["seed"], seed);
+    assert!(lines[0]["png"].is_null());
+
+    fs::remove_dir_all(&out_dir).unwrap();
+}
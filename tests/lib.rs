@@ -1,10 +1,41 @@
 use radix_common::network::NetworkDefinition;
 use rand::prelude::*;
+use resvg;
 use scrypto_test::prelude::*;
 use scrypto_test::utils::dump_manifest_to_file_system;
 use std::fs;
-use svgenesis::{svgenesis_test::*, types::SVGenesisNFT};
-use resvg;
+use svg::node::element::{Definitions, Element, LinearGradient, Rectangle, Stop};
+use svg::{Document, Node};
+use svgenesis::{
+    data_uri::{
+        base64_svg_data_uri, decode_svg_data_uri, percent_encoded_svg_data_uri, svg_data_uri,
+    },
+    errors::SVGenesisError,
+    hsl::HSL,
+    layers::{
+        all_layers, background_layers, big_element_layers,
+        big_elements::{
+            big_element_pill_ball::BigElementPillBall, big_element_square::BigElementSquare,
+        },
+        frame_layers,
+        frames::frame_straight::FrameStraight,
+        overlay_layers,
+        overlays::overlay_diamond::OverlayDiamond,
+        small_element_layers, DefaultWeights, Exclusion, Layer, LayerCategory, MAX_LAYER_WEIGHT,
+    },
+    nft_generator::{
+        canonical_fingerprint, compose_nft_image_with_config, compose_nft_image_with_version,
+        composition::{ComposedLayer, Gradient, GradientStop, Paint, Shape, ShapeKind, Transform},
+        generate_nft_image_data_with_config, generate_nft_image_data_with_version, renderer,
+        streams::derive_seed,
+        Chance, Composition, Condition, GeneratorConfig, GeneratorVersion, RandomStreams, Stage,
+    },
+    rng::Random,
+    svg_data::SVGData,
+    svgenesis_test::*,
+    types::{MintPhase, SVGenesisNFT, StorageMode},
+    utils::{IdAllocation, IdAllocator},
+};
 
 #[test]
 fn can_mint_nft() -> Result<(), RuntimeError> {
@@ -13,13 +44,21 @@ fn can_mint_nft() -> Result<(), RuntimeError> {
     let package_address =
         PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
 
-    let (mut svgenesis, _) = SVGenesis::instantiate(package_address, &mut env)?;
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
 
     let mut data = [0u8; 128];
     rand::thread_rng().fill_bytes(&mut data);
 
     // Act
-    let first_mint = svgenesis.mint_nft(data.to_vec(), &mut env);
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let first_mint = svgenesis.mint_nft(data.to_vec(), payment, &mut env);
 
     // Assert
     assert!(first_mint.is_ok());
@@ -34,7 +73,14 @@ fn cannot_mint_with_same_seed() -> Result<(), RuntimeError> {
     let package_address =
         PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
 
-    let (mut svgenesis, _) = SVGenesis::instantiate(package_address, &mut env)?;
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
 
     env.disable_auth_module();
 
@@ -42,11 +88,13 @@ fn cannot_mint_with_same_seed() -> Result<(), RuntimeError> {
     rand::thread_rng().fill_bytes(&mut data);
 
     // Act
-    let _first_mint = svgenesis.mint_nft(data.to_vec(), &mut env)?;
-    let second_mint = svgenesis.mint_nft(data.to_vec(), &mut env);
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let _first_mint = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let second_mint = svgenesis.mint_nft(data.to_vec(), payment, &mut env);
 
     // Assert
-    assert!(second_mint.is_err());
+    assert_svgenesis_error(second_mint, SVGenesisError::SeedUsed);
 
     Ok(())
 }
@@ -58,7 +106,14 @@ fn cannot_mint_with_wrong_seed_length() -> Result<(), RuntimeError> {
     let package_address =
         PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
 
-    let (mut svgenesis, _) = SVGenesis::instantiate(package_address, &mut env)?;
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
 
     env.disable_auth_module();
 
@@ -66,16 +121,372 @@ fn cannot_mint_with_wrong_seed_length() -> Result<(), RuntimeError> {
     rand::thread_rng().fill_bytes(&mut data);
 
     // Act
-    let result = svgenesis.mint_nft(data.to_vec(), &mut env);
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let result = svgenesis.mint_nft(data.to_vec(), payment, &mut env);
 
     // Assert
-    assert!(result.is_err());
+    assert_svgenesis_error(result, SVGenesisError::InvalidSeedLength);
 
     Ok(())
 }
 
 #[test]
-fn owner_can_mint_admin_badge() {
+fn cannot_use_empty_seed() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let mint_result = svgenesis.mint_nft(vec![], payment, &mut env);
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let batch_result = svgenesis.mint_nfts(vec![data.to_vec(), vec![]], payment, &mut env);
+    let preview_result = svgenesis.preview(vec![], &mut env);
+
+    // Assert
+    assert_svgenesis_error(mint_result, SVGenesisError::EmptySeed);
+    assert_svgenesis_error(
+        batch_result,
+        SVGenesisError::InvalidBatchSeed {
+            index: 1,
+            error: Box::new(SVGenesisError::EmptySeed),
+        },
+    );
+    assert_svgenesis_error(preview_result, SVGenesisError::EmptySeed);
+
+    Ok(())
+}
+
+#[test]
+fn can_mint_nft_batch() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        Some(dec!(10)),
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let seeds: Vec<Vec<u8>> = (0..5)
+        .map(|_| {
+            let mut data = [0u8; 128];
+            rand::thread_rng().fill_bytes(&mut data);
+            data.to_vec()
+        })
+        .collect();
+
+    // Act
+    let payment = xrd_bucket(dec!(60), &mut env)?;
+    let (nft_bucket, change) = svgenesis.mint_nfts(seeds, payment, &mut env)?;
+
+    // Assert
+    assert_eq!(nft_bucket.amount(&mut env)?, dec!(5));
+    assert_eq!(change.amount(&mut env)?, dec!(10));
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_nft_batch_with_duplicate_seed() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let mut seeds: Vec<Vec<u8>> = (0..3)
+        .map(|_| {
+            let mut data = [0u8; 128];
+            rand::thread_rng().fill_bytes(&mut data);
+            data.to_vec()
+        })
+        .collect();
+    seeds.push(seeds[1].clone());
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let result = svgenesis.mint_nfts(seeds, payment, &mut env);
+
+    // Assert
+    assert_svgenesis_error(
+        result,
+        SVGenesisError::InvalidBatchSeed {
+            index: 3,
+            error: Box::new(SVGenesisError::DuplicateSeedInBatch),
+        },
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_nft_batch_with_used_seed() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let seeds: Vec<Vec<u8>> = (0..3)
+        .map(|_| {
+            let mut data = [0u8; 128];
+            rand::thread_rng().fill_bytes(&mut data);
+            data.to_vec()
+        })
+        .collect();
+
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    svgenesis.mint_nft(seeds[2].clone(), payment, &mut env)?;
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let result = svgenesis.mint_nfts(seeds, payment, &mut env);
+
+    // Assert
+    assert_svgenesis_error(
+        result,
+        SVGenesisError::InvalidBatchSeed {
+            index: 2,
+            error: Box::new(SVGenesisError::SeedUsed),
+        },
+    );
+
+    Ok(())
+}
+
+#[test]
+fn preview_matches_mint() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    // Act
+    let (preview_uri, preview_layers, exists_before_mint) =
+        svgenesis.preview(data.to_vec(), &mut env)?;
+
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let (nft_bucket, _) = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+
+    let (_, _, exists_after_mint) = svgenesis.preview(data.to_vec(), &mut env)?;
+
+    let resource_manager = ResourceManager(nft_bucket.resource_address(&mut env)?);
+    let nft_data = resource_manager.get_non_fungible_data::<_, _, SVGenesisNFT>(
+        nft_bucket
+            .non_fungible_local_ids(&mut env)?
+            .first()
+            .unwrap()
+            .clone(),
+        &mut env,
+    )?;
+
+    // Assert
+    assert!(!exists_before_mint);
+    assert!(exists_after_mint);
+    assert_eq!(preview_uri, nft_data.key_image_url.as_str());
+    assert_eq!(preview_layers, nft_data.layers);
+    assert_eq!(nft_data.traits.len(), nft_data.layers.len());
+
+    Ok(())
+}
+
+// The component runs in the engine's WebAssembly interpreter, so this compares the generator built for wasm32, as used
+// by the wasm bindings, to the native build without needing Node
+#[test]
+fn wasm_preview_matches_native_preview() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    for seed in golden_seeds() {
+        // Act
+        let (native_svg, native_layers) = svgenesis::nft_generator::generate_nft_image_data(&seed);
+        let (wasm_uri, wasm_layers, _) = svgenesis.preview(seed, &mut env)?;
+
+        // Assert
+        assert_eq!(wasm_uri, svg_data_uri(&native_svg));
+        assert_eq!(wasm_layers, native_layers);
+    }
+
+    Ok(())
+}
+
+// The preview module hashes with blake2, so it only exists with the features that use it
+#[cfg(any(feature = "cli", feature = "wasm"))]
+#[test]
+fn preview_hash_matches_component_hash() {
+    for seed in golden_seeds() {
+        // Act
+        let preview = svgenesis::preview::preview_seed(&hex::encode(&seed)).unwrap();
+
+        // Assert
+        assert_eq!(
+            preview.hash,
+            hash(canonical_fingerprint(&preview.svg, &preview.layers)).to_string()
+        );
+    }
+}
+
+#[test]
+fn render_matches_stored_svg_data() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let (nft_bucket, _) = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+    let nft_id = nft_bucket
+        .non_fungible_local_ids(&mut env)?
+        .first()
+        .unwrap()
+        .clone();
+
+    // Act
+    let rendered_svg = svgenesis.render(nft_id.clone(), &mut env)?;
+
+    // Assert
+    let resource_manager = ResourceManager(nft_bucket.resource_address(&mut env)?);
+    let nft_data =
+        resource_manager.get_non_fungible_data::<_, _, SVGenesisNFT>(nft_id, &mut env)?;
+
+    assert_eq!(nft_data.seed, data.to_vec());
+    assert_eq!(nft_data.svg_data.decode().unwrap(), rendered_svg);
+
+    Ok(())
+}
+
+#[test]
+fn rarity_counts_traits_of_minted_nfts() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let seeds: Vec<Vec<u8>> = golden_seeds().into_iter().take(2).collect();
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let (nft_bucket, _) = svgenesis.mint_nfts(seeds, payment, &mut env)?;
+    let nft_id = nft_bucket
+        .non_fungible_local_ids(&mut env)?
+        .first()
+        .unwrap()
+        .clone();
+
+    env.disable_auth_module();
+
+    // Act
+    let rarity = svgenesis.rarity(nft_id.clone(), &mut env)?;
+    let written_score = svgenesis.update_rarity_score(nft_id.clone(), &mut env)?;
+
+    // Assert
+    let resource_manager = ResourceManager(nft_bucket.resource_address(&mut env)?);
+    let nft_data =
+        resource_manager.get_non_fungible_data::<_, _, SVGenesisNFT>(nft_id, &mut env)?;
+
+    for nft_trait in &nft_data.traits {
+        assert!(rarity
+            .frequencies
+            .iter()
+            .any(|trait_frequency| trait_frequency.trait_key == nft_trait.keys()[0]));
+    }
+
+    let mut expected_score = Decimal::ZERO;
+    for trait_frequency in &rarity.frequencies {
+        assert!((1..=2).contains(&trait_frequency.count));
+        assert_eq!(
+            trait_frequency.frequency,
+            Decimal::from(trait_frequency.count) / dec!(2)
+        );
+        expected_score += dec!(2) / Decimal::from(trait_frequency.count);
+    }
+
+    assert_eq!(rarity.score, expected_score);
+    assert_eq!(written_score, rarity.score);
+    assert_eq!(nft_data.rarity_score, Some(rarity.score));
+
+    Ok(())
+}
+
+#[test]
+fn only_admin_can_update_rarity_score() {
     // Setup the environment
     let mut ledger = LedgerSimulatorBuilder::new().without_kernel_trace().build();
 
@@ -92,7 +503,12 @@ fn owner_can_mint_admin_badge() {
             package_address,
             "SVGenesis",
             "instantiate",
-            manifest_args!(),
+            manifest_args!(
+                None::<Decimal>,
+                None::<u64>,
+                None::<ComponentAddress>,
+                StorageMode::Full
+            ),
         )
         .deposit_batch(account)
         .build();
@@ -105,8 +521,12 @@ fn owner_can_mint_admin_badge() {
     // Get the component and resource addresses
     let component = receipt.expect_commit_success().new_component_addresses()[0];
     let owner_badge = receipt.expect_commit_success().new_resource_addresses()[0];
+    let admin_badge = receipt.expect_commit_success().new_resource_addresses()[1];
+
+    // Mint an admin badge and an NFT
+    let mut seed = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut seed);
 
-    // Mint admin badge
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
         .call_method(
@@ -115,40 +535,51 @@ fn owner_can_mint_admin_badge() {
             manifest_args!(owner_badge, dec!(1)),
         )
         .call_method(component, "mint_admin_badge", manifest_args!())
+        .withdraw_from_account(account, XRD, dec!(0))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(component, "mint_nft", |lookup| {
+            manifest_args!(seed.to_vec(), lookup.bucket("payment"))
+        })
         .deposit_batch(account)
         .build();
 
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Try to update the rarity score without the admin badge
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component,
+            "update_rarity_score",
+            manifest_args!(NonFungibleLocalId::integer(1)),
+        )
+        .build();
+
     let receipt = ledger.execute_manifest(
         manifest,
         vec![NonFungibleGlobalId::from_public_key(&public_key)],
     );
 
-    // Assert
-    receipt.expect_commit_success();
-}
-
-// See if we run into any limits when minting a ton of these NFTs
-// #[test]
-fn limits_test() {
-    // Setup the environment
-    let mut ledger = LedgerSimulatorBuilder::new().without_kernel_trace().build();
-
-    // Create an account
-    let (public_key, _private_key, account) = ledger.new_allocated_account();
-
-    // Publish the package
-    let package_address = ledger.compile_and_publish(this_package!());
+    receipt.expect_commit_failure();
 
-    // Instantiate the component
+    // Try again with the admin badge
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
-        .call_function(
-            package_address,
-            "SVGenesis",
-            "instantiate",
-            manifest_args!(),
+        .call_method(
+            account,
+            "create_proof_of_amount",
+            manifest_args!(admin_badge, dec!(1)),
+        )
+        .call_method(
+            component,
+            "update_rarity_score",
+            manifest_args!(NonFungibleLocalId::integer(1)),
         )
-        .deposit_batch(account)
         .build();
 
     let receipt = ledger.execute_manifest(
@@ -156,129 +587,2263 @@ fn limits_test() {
         vec![NonFungibleGlobalId::from_public_key(&public_key)],
     );
 
-    // Get the component address
-    let component = receipt.expect_commit_success().new_component_addresses()[0];
+    // Assert
+    receipt.expect_commit_success();
+}
 
-    // Perform a mint X times
-    for i in 0..500000 {
-        // Generate a seed
-        let mut seed = [0u8; 128];
-        rand::thread_rng().fill_bytes(&mut seed);
+#[test]
+fn can_mint_nft_in_compact_storage_mode() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
 
-        // Mint NFT
-        let manifest = ManifestBuilder::new()
-            .lock_fee_from_faucet()
-            .call_method(component, "mint_nft", manifest_args!(seed.to_vec()))
-            .deposit_batch(account)
-            .build();
+    let storage_mode = StorageMode::Compact {
+        image_base_url: "https://svgenesis.example/render/".to_string(),
+    };
+    let (mut svgenesis, _) =
+        SVGenesis::instantiate(None, None, None, storage_mode, package_address, &mut env)?;
 
-        let receipt = ledger.execute_manifest(
-            manifest,
-            vec![NonFungibleGlobalId::from_public_key(&public_key)],
-        );
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
 
-        // Print fee cost
-        println!(
-            "#{} execution cost: {} XRD",
-            i + 1,
-            receipt.fee_summary.total_cost()
-        );
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let (nft_bucket, _) = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+    let nft_id = nft_bucket
+        .non_fungible_local_ids(&mut env)?
+        .first()
+        .unwrap()
+        .clone();
+    let rendered_svg = svgenesis.render(nft_id.clone(), &mut env)?;
 
-        // Assert
-        receipt.expect_commit_success();
-    }
+    // Assert
+    let resource_manager = ResourceManager(nft_bucket.resource_address(&mut env)?);
+    let nft_data =
+        resource_manager.get_non_fungible_data::<_, _, SVGenesisNFT>(nft_id, &mut env)?;
+    let (expected_svg, expected_layers) =
+        svgenesis::nft_generator::generate_nft_image_data(&data.to_vec());
+
+    assert!(nft_data.svg_data.is_empty());
+    assert_eq!(
+        nft_data.key_image_url.as_str(),
+        "https://svgenesis.example/render/1"
+    );
+    assert_eq!(nft_data.seed, data.to_vec());
+    assert_eq!(
+        nft_data.generator_version,
+        svgenesis::nft_generator::GENERATOR_VERSION
+    );
+    assert_eq!(nft_data.layers, expected_layers);
+    assert_eq!(rendered_svg, expected_svg);
+    assert_eq!(
+        svgenesis::nft_generator::render_nft_image(&nft_data.seed, nft_data.generator_version),
+        Some(expected_svg)
+    );
+
+    Ok(())
 }
 
-// Mint a load of NFTs for test/review purposes
 #[test]
-fn mint_nft_batch() -> Result<(), RuntimeError> {
-    // Instantiate the component
+fn minted_nfts_get_sequential_ids() -> Result<(), RuntimeError> {
+    // Arrange
     let mut env = TestEnvironment::new();
     let package_address =
         PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
 
-    let (mut svgenesis, _) = SVGenesis::instantiate(package_address, &mut env)?;
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
 
-    // Disable auth and limits so we don't run into unnecessary issues here
-    env.disable_auth_module();
-    env.disable_limits_module();
+    // The first golden seeds whose images define gradients
+    let seeds: Vec<Vec<u8>> = golden_seeds()
+        .into_iter()
+        .filter(|seed| {
+            !definition_ids(&svgenesis::nft_generator::generate_nft_image_data(seed).0).is_empty()
+        })
+        .take(3)
+        .collect();
 
-    // Create images directory if necessary
-    let _ = fs::create_dir_all("test_images");
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let (nft_bucket, _) = svgenesis.mint_nfts(seeds.clone(), payment, &mut env)?;
 
-    // Mint X NFTs and write them to disk
-    for i in 1..1001 {
-        // Generate a seed
-        let mut data = [0u8; 128];
-        rand::thread_rng().fill_bytes(&mut data);
+    // Assert
+    let resource_manager = ResourceManager(nft_bucket.resource_address(&mut env)?);
+    for nft_id in nft_bucket.non_fungible_local_ids(&mut env)? {
+        let nft_data =
+            resource_manager.get_non_fungible_data::<_, _, SVGenesisNFT>(nft_id, &mut env)?;
+        let svg_data = nft_data.svg_data.decode().unwrap();
+        let ids = definition_ids(&svg_data);
 
-        // Mint the NFT
-        let nft_bucket = svgenesis.mint_nft(data.to_vec(), &mut env)?;
+        assert!(seeds.contains(&nft_data.seed));
+        assert!(!ids.is_empty());
+        assert!(ids.iter().all(|id| is_sequential_id(id)), "{svg_data}");
+    }
 
-        // Get the NFT data
-        let resource_manager = ResourceManager(nft_bucket.resource_address(&mut env)?);
-        let nft_data = resource_manager.get_non_fungible_data::<_, _, SVGenesisNFT>(
-            nft_bucket
-                .non_fungible_local_ids(&mut env)?
-                .first()
-                .unwrap()
-                .clone(),
-            &mut env,
-        )?;
+    Ok(())
+}
+
+#[test]
+fn can_reroll_nft() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+    let mut new_data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut new_data);
+
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let (nft_bucket, _) = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+    let nft_id = nft_bucket
+        .non_fungible_local_ids(&mut env)?
+        .first()
+        .unwrap()
+        .clone();
+
+    // Act
+    let rerolled_bucket = svgenesis.reroll(nft_bucket, new_data.to_vec(), &mut env)?;
+
+    // Assert
+    let resource_manager = ResourceManager(rerolled_bucket.resource_address(&mut env)?);
+    let nft_data = resource_manager.get_non_fungible_data::<_, _, SVGenesisNFT>(
+        rerolled_bucket
+            .non_fungible_local_ids(&mut env)?
+            .first()
+            .unwrap()
+            .clone(),
+        &mut env,
+    )?;
+    let (expected_svg, expected_layers) =
+        svgenesis::nft_generator::generate_nft_image_data(&new_data.to_vec());
+
+    assert_eq!(
+        rerolled_bucket
+            .non_fungible_local_ids(&mut env)?
+            .first()
+            .unwrap(),
+        &nft_id
+    );
+    assert_eq!(nft_data.layers, expected_layers);
+    assert_eq!(nft_data.svg_data.decode().unwrap(), expected_svg);
+    assert_eq!(
+        svgenesis.seed_used(new_data.to_vec(), &mut env)?,
+        (true, Some(nft_id.clone()))
+    );
+    assert_eq!(
+        svgenesis.seed_used(data.to_vec(), &mut env)?,
+        (true, Some(nft_id))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_reroll_with_used_seed() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let (nft_bucket, _) = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+
+    // Act
+    let result = svgenesis.reroll(nft_bucket, data.to_vec(), &mut env);
+
+    // Assert
+    assert_svgenesis_error(result, SVGenesisError::SeedUsed);
+
+    Ok(())
+}
+
+#[test]
+fn cannot_instantiate_with_invalid_settings() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    // Act
+    let negative_price = SVGenesis::instantiate(
+        Some(dec!(-1)),
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    );
+    let zero_supply = SVGenesis::instantiate(
+        None,
+        Some(0),
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    );
+
+    // Assert
+    assert_svgenesis_error(negative_price, SVGenesisError::NegativeMintPrice);
+    assert_svgenesis_error(zero_supply, SVGenesisError::ZeroMaxSupply);
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_empty_nft_batch() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let result = svgenesis.mint_nfts(vec![], payment, &mut env);
+
+    // Assert
+    assert_svgenesis_error(result, SVGenesisError::NoSeeds);
+
+    Ok(())
+}
+
+#[test]
+fn cannot_use_randomness_when_not_enabled() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    env.disable_auth_module();
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let request_result = svgenesis.request_mint(payment, &mut env);
+    let retry_result = svgenesis.retry_mint(1, &mut env);
+
+    // Assert
+    assert_svgenesis_error(request_result, SVGenesisError::RandomnessNotEnabled);
+    assert_svgenesis_error(retry_result, SVGenesisError::NoPendingMint);
+
+    Ok(())
+}
+
+#[test]
+fn cannot_reroll_invalid_bucket() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let seeds: Vec<Vec<u8>> = (0..3)
+        .map(|_| {
+            let mut data = [0u8; 128];
+            rand::thread_rng().fill_bytes(&mut data);
+            data.to_vec()
+        })
+        .collect();
+
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let (nft_bucket, _) = svgenesis.mint_nfts(seeds[..2].to_vec(), payment, &mut env)?;
+
+    // Act
+    let xrd = xrd_bucket(dec!(1), &mut env)?;
+    let xrd_result = svgenesis.reroll(xrd, seeds[2].clone(), &mut env);
+    let batch_result = svgenesis.reroll(nft_bucket, seeds[2].clone(), &mut env);
+
+    // Assert
+    assert_svgenesis_error(xrd_result, SVGenesisError::NotAnSVGenesisNft);
+    assert_svgenesis_error(batch_result, SVGenesisError::InvalidRerollAmount);
+
+    Ok(())
+}
+
+#[test]
+fn admin_can_change_layer_weights() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    env.disable_auth_module();
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    // Act
+    for (layer, _) in background_layers() {
+        if layer.layer_name() != "BackgroundRectangle" {
+            svgenesis.set_layer_weight(layer.layer_name(), 0, &mut env)?;
+        }
+    }
+    svgenesis.set_layer_weight("SmallElementCube".to_string(), 10, &mut env)?;
+    svgenesis.reset_layer_weight("SmallElementCube".to_string(), &mut env)?;
+
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let (nft_bucket, _) = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+
+    // Assert
+    let resource_manager = ResourceManager(nft_bucket.resource_address(&mut env)?);
+    let nft_data = resource_manager.get_non_fungible_data::<_, _, SVGenesisNFT>(
+        nft_bucket
+            .non_fungible_local_ids(&mut env)?
+            .first()
+            .unwrap()
+            .clone(),
+        &mut env,
+    )?;
+
+    assert_eq!(nft_data.layers[0], "BackgroundRectangle");
+    assert_eq!(
+        svgenesis.layer_weight("BackgroundChevron".to_string(), &mut env)?,
+        0
+    );
+    assert_eq!(
+        svgenesis.layer_weight("SmallElementCube".to_string(), &mut env)?,
+        100
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_set_invalid_layer_weights() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+    let storage_mode = StorageMode::Compact {
+        image_base_url: "https://svgenesis.example/render/".to_string(),
+    };
+    let (mut compact_svgenesis, _) =
+        SVGenesis::instantiate(None, None, None, storage_mode, package_address, &mut env)?;
+
+    env.disable_auth_module();
+
+    for (layer, _) in background_layers().into_iter().skip(1) {
+        svgenesis.set_layer_weight(layer.layer_name(), 0, &mut env)?;
+    }
+    for (layer, _) in big_element_layers()
+        .into_iter()
+        .chain(small_element_layers().into_iter().skip(1))
+    {
+        svgenesis.set_layer_weight(layer.layer_name(), 0, &mut env)?;
+    }
+    let last_element = small_element_layers()[0].0.layer_name();
+
+    // Act
+    let unknown_result = svgenesis.set_layer_weight("BackgroundUnicorn".to_string(), 10, &mut env);
+    let last_background_result =
+        svgenesis.set_layer_weight("BackgroundRectangle".to_string(), 0, &mut env);
+    let last_element_result = svgenesis.set_layer_weight(last_element, 0, &mut env);
+    let too_high_result = svgenesis.set_layer_weight(
+        "SmallElementCube".to_string(),
+        MAX_LAYER_WEIGHT + 1,
+        &mut env,
+    );
+    let compact_result =
+        compact_svgenesis.set_layer_weight("SmallElementCube".to_string(), 10, &mut env);
+
+    // Assert
+    assert_svgenesis_error(unknown_result, SVGenesisError::UnknownLayer);
+    assert_svgenesis_error(last_background_result, SVGenesisError::NoBackgroundsLeft);
+    assert_svgenesis_error(last_element_result, SVGenesisError::NoElementsLeft);
+    assert_svgenesis_error(
+        too_high_result,
+        SVGenesisError::LayerWeightTooHigh {
+            max_weight: MAX_LAYER_WEIGHT,
+        },
+    );
+    assert_svgenesis_error(compact_result, SVGenesisError::LayerWeightsLocked);
+
+    Ok(())
+}
+
+#[test]
+fn owner_can_mint_admin_badge() {
+    // Setup the environment
+    let mut ledger = LedgerSimulatorBuilder::new().without_kernel_trace().build();
+
+    // Create an account
+    let (public_key, _private_key, account) = ledger.new_allocated_account();
+
+    // Publish the package
+    let package_address = ledger.compile_and_publish(this_package!());
+
+    // Instantiate the component
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "SVGenesis",
+            "instantiate",
+            manifest_args!(
+                None::<Decimal>,
+                None::<u64>,
+                None::<ComponentAddress>,
+                StorageMode::Full
+            ),
+        )
+        .deposit_batch(account)
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Get the component and resource addresses
+    let component = receipt.expect_commit_success().new_component_addresses()[0];
+    let owner_badge = receipt.expect_commit_success().new_resource_addresses()[0];
+
+    // Mint admin badge
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            "create_proof_of_amount",
+            manifest_args!(owner_badge, dec!(1)),
+        )
+        .call_method(component, "mint_admin_badge", manifest_args!())
+        .deposit_batch(account)
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_mint_paid_nft_and_get_change() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        Some(dec!(10)),
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    // Act
+    let payment = xrd_bucket(dec!(25), &mut env)?;
+    let (nft_bucket, change) = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+
+    env.disable_auth_module();
+    let fees = svgenesis.withdraw_fees(&mut env)?;
+
+    // Assert
+    assert_eq!(nft_bucket.amount(&mut env)?, dec!(1));
+    assert_eq!(change.amount(&mut env)?, dec!(15));
+    assert_eq!(fees.amount(&mut env)?, dec!(10));
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_paid_nft_with_insufficient_payment() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        Some(dec!(10)),
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    // Act
+    let payment = xrd_bucket(dec!(9.99), &mut env)?;
+    let result = svgenesis.mint_nft(data.to_vec(), payment, &mut env);
+
+    // Assert
+    assert_svgenesis_error(
+        result,
+        SVGenesisError::InsufficientPayment {
+            mint_price: dec!(10),
+        },
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_paid_nft_with_wrong_resource() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        Some(dec!(10)),
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let fake_xrd = ResourceBuilder::new_fungible(OwnerRole::None)
+        .divisibility(18)
+        .mint_initial_supply(dec!(100), &mut env)?;
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    // Act
+    let result = svgenesis.mint_nft(data.to_vec(), fake_xrd.0, &mut env);
+
+    // Assert
+    assert_svgenesis_error(result, SVGenesisError::WrongPaymentResource);
+
+    Ok(())
+}
+
+#[test]
+fn admin_can_change_mint_price() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    env.disable_auth_module();
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    // Act
+    svgenesis.set_mint_price(Some(dec!(5)), &mut env)?;
+    let payment = xrd_bucket(dec!(5), &mut env)?;
+    let (_, change) = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+    let fees = svgenesis.withdraw_fees(&mut env)?;
+
+    // Assert
+    assert_eq!(change.amount(&mut env)?, dec!(0));
+    assert_eq!(fees.amount(&mut env)?, dec!(5));
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_beyond_max_supply() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        Some(1),
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+    let mut other_data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut other_data);
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let first_mint = svgenesis.mint_nft(data.to_vec(), payment, &mut env);
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let second_mint = svgenesis.mint_nft(other_data.to_vec(), payment, &mut env);
+    let (phase, minted, max_supply) = svgenesis.mint_status(&mut env)?;
+
+    // Assert
+    assert!(first_mint.is_ok());
+    assert_svgenesis_error(second_mint, SVGenesisError::SoldOut);
+    assert_eq!(phase, MintPhase::SoldOut);
+    assert_eq!(minted, 1);
+    assert_eq!(max_supply, Some(1));
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_when_closed() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    env.disable_auth_module();
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    // Act
+    svgenesis.set_phase(MintPhase::Closed, &mut env)?;
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let closed_mint = svgenesis.mint_nft(data.to_vec(), payment, &mut env);
+
+    svgenesis.set_phase(MintPhase::Public, &mut env)?;
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let public_mint = svgenesis.mint_nft(data.to_vec(), payment, &mut env);
+
+    // Assert
+    assert_svgenesis_error(closed_mint, SVGenesisError::MintClosed);
+    assert!(public_mint.is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn cannot_reopen_sold_out_mint() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        Some(1),
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    env.disable_auth_module();
+
+    let mut data = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut data);
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+    let result = svgenesis.set_phase(MintPhase::Public, &mut env);
+
+    // Assert
+    assert_svgenesis_error(result, SVGenesisError::CannotReopenMint);
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_batch_beyond_remaining_supply() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        Some(3),
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let seeds: Vec<Vec<u8>> = (0..4)
+        .map(|_| {
+            let mut data = [0u8; 128];
+            rand::thread_rng().fill_bytes(&mut data);
+            data.to_vec()
+        })
+        .collect();
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    svgenesis.mint_nft(seeds[0].clone(), payment, &mut env)?;
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let result = svgenesis.mint_nfts(seeds[1..].to_vec(), payment, &mut env);
+
+    // Assert
+    assert_svgenesis_error(result, SVGenesisError::SupplyExhausted { remaining: 2 });
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_duplicate_image() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let (weights, seed, other_seed) = seeds_with_same_image();
+    env.disable_auth_module();
+    for (layer_name, weight) in weights {
+        svgenesis.set_layer_weight(layer_name, weight, &mut env)?;
+    }
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    svgenesis.mint_nft(seed, payment, &mut env)?;
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let result = svgenesis.mint_nft(other_seed, payment, &mut env);
+
+    // Assert
+    assert_svgenesis_error(result, SVGenesisError::DuplicateImage);
+
+    Ok(())
+}
+
+#[test]
+fn cannot_mint_duplicate_image_in_batch() -> Result<(), RuntimeError> {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    let (weights, seed, other_seed) = seeds_with_same_image();
+    env.disable_auth_module();
+    for (layer_name, weight) in weights {
+        svgenesis.set_layer_weight(layer_name, weight, &mut env)?;
+    }
+
+    // Act
+    let payment = xrd_bucket(dec!(0), &mut env)?;
+    let result = svgenesis.mint_nfts(vec![seed, other_seed], payment, &mut env);
+
+    // Assert
+    assert_svgenesis_error(
+        result,
+        SVGenesisError::InvalidBatchSeed {
+            index: 1,
+            error: Box::new(SVGenesisError::DuplicateImageInBatch),
+        },
+    );
+
+    Ok(())
+}
+
+#[test]
+fn only_allowlisted_can_mint_during_allowlist_phase() {
+    // Setup the environment
+    let mut ledger = LedgerSimulatorBuilder::new().without_kernel_trace().build();
+
+    // Create an account
+    let (public_key, _private_key, account) = ledger.new_allocated_account();
+
+    // Publish the package
+    let package_address = ledger.compile_and_publish(this_package!());
+
+    // Instantiate the component
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "SVGenesis",
+            "instantiate",
+            manifest_args!(
+                None::<Decimal>,
+                None::<u64>,
+                None::<ComponentAddress>,
+                StorageMode::Full
+            ),
+        )
+        .deposit_batch(account)
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Get the component and resource addresses
+    let component = receipt.expect_commit_success().new_component_addresses()[0];
+    let owner_badge = receipt.expect_commit_success().new_resource_addresses()[0];
+    let admin_badge = receipt.expect_commit_success().new_resource_addresses()[1];
+    let allowlist_badge = receipt.expect_commit_success().new_resource_addresses()[2];
+
+    // Mint an admin badge and move to the allowlist phase
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            "create_proof_of_amount",
+            manifest_args!(owner_badge, dec!(1)),
+        )
+        .call_method(component, "mint_admin_badge", manifest_args!())
+        .deposit_batch(account)
+        .call_method(
+            account,
+            "create_proof_of_amount",
+            manifest_args!(admin_badge, dec!(1)),
+        )
+        .call_method(component, "set_phase", manifest_args!(MintPhase::Allowlist))
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Try to mint without an allowlist badge
+    let mut seed = [0u8; 128];
+    rand::thread_rng().fill_bytes(&mut seed);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!(0))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(component, "mint_nft", |lookup| {
+            manifest_args!(seed.to_vec(), lookup.bucket("payment"))
+        })
+        .deposit_batch(account)
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    receipt.expect_commit_failure();
+
+    // Mint an allowlist badge and try again
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            "create_proof_of_amount",
+            manifest_args!(admin_badge, dec!(1)),
+        )
+        .call_method(component, "mint_allowlist_badge", manifest_args!())
+        .deposit_batch(account)
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            "create_proof_of_amount",
+            manifest_args!(allowlist_badge, dec!(1)),
+        )
+        .withdraw_from_account(account, XRD, dec!(0))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(component, "mint_nft", |lookup| {
+            manifest_args!(seed.to_vec(), lookup.bucket("payment"))
+        })
+        .deposit_batch(account)
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn can_mint_with_random_component_callback() {
+    // Setup the environment with a mock RandomComponent
+    let (mut ledger, public_key, account, component, random_component, nft_resource) =
+        instantiate_with_mock_random();
+
+    // Request a mint, which deposits a placeholder NFT
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!(0))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(component, "request_mint", |lookup| {
+            manifest_args!(lookup.bucket("payment"))
+        })
+        .deposit_batch(account)
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    let placeholder =
+        ledger.get_non_fungible_data::<SVGenesisNFT>(nft_resource, NonFungibleLocalId::integer(1));
+    assert!(placeholder.layers.is_empty());
+
+    // Nobody but the RandomComponent can deliver the randomness
+    let mut random_seed = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut random_seed);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component,
+            "finish_mint",
+            manifest_args!(1u32, random_seed.to_vec()),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_failure();
+
+    // Let the mock RandomComponent deliver the randomness
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            random_component,
+            "execute",
+            manifest_args!(random_seed.to_vec()),
+        )
+        .build();
+
+    ledger
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Assert
+    let nft_data =
+        ledger.get_non_fungible_data::<SVGenesisNFT>(nft_resource, NonFungibleLocalId::integer(1));
+    let (expected_svg, expected_layers) =
+        svgenesis::nft_generator::generate_nft_image_data(&random_seed.to_vec());
+
+    assert_eq!(nft_data.layers, expected_layers);
+    assert_eq!(nft_data.svg_data.decode().unwrap(), expected_svg);
+}
+
+#[test]
+fn cannot_use_nft_while_image_is_pending() {
+    // Arrange
+    let (mut ledger, public_key, account, component, _, _) = instantiate_with_mock_random();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!(0))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(component, "request_mint", |lookup| {
+            manifest_args!(lookup.bucket("payment"))
+        })
+        .deposit_batch(account)
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component,
+            "render",
+            manifest_args!(NonFungibleLocalId::integer(1)),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+
+    // Assert
+    assert_receipt_svgenesis_error(&receipt, SVGenesisError::ImagePending);
+}
+
+#[test]
+fn cannot_finish_mint_without_unique_image() {
+    // Arrange
+    let (mut ledger, public_key, account, component, random_component, _) =
+        instantiate_with_mock_random();
+
+    // Use the random seed and every seed that would be derived from it
+    let mut random_seed = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut random_seed);
+
+    let mut used_seeds = vec![random_seed.to_vec()];
+    while used_seeds.len() < 10 {
+        used_seeds.push(hash(used_seeds.last().unwrap()).0.to_vec());
+    }
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!(0))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(component, "mint_nfts", |lookup| {
+            manifest_args!(used_seeds, lookup.bucket("payment"))
+        })
+        .withdraw_from_account(account, XRD, dec!(0))
+        .take_all_from_worktop(XRD, "request_payment")
+        .call_method_with_name_lookup(component, "request_mint", |lookup| {
+            manifest_args!(lookup.bucket("request_payment"))
+        })
+        .deposit_batch(account)
+        .build();
+
+    ledger
+        .execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            random_component,
+            "execute",
+            manifest_args!(random_seed.to_vec()),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+
+    // Assert
+    assert_receipt_svgenesis_error(
+        &receipt,
+        SVGenesisError::NoUniqueImage {
+            error: Box::new(SVGenesisError::SeedUsed),
+        },
+    );
+}
+
+// See if we run into any limits when minting a lot of these NFTs, and check they store compressed SVG data
+#[test]
+fn limits_test() {
+    // Setup the environment
+    let mut ledger = LedgerSimulatorBuilder::new().without_kernel_trace().build();
+
+    // Create an account
+    let (public_key, _private_key, account) = ledger.new_allocated_account();
+
+    // Publish the package
+    let package_address = ledger.compile_and_publish(this_package!());
+
+    // Instantiate the component
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "SVGenesis",
+            "instantiate",
+            manifest_args!(
+                None::<Decimal>,
+                None::<u64>,
+                None::<ComponentAddress>,
+                StorageMode::Full
+            ),
+        )
+        .deposit_batch(account)
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Get the component and NFT resource addresses
+    let component = receipt.expect_commit_success().new_component_addresses()[0];
+    let nft_resource = nft_resource(&receipt);
+
+    // Perform a handful of mints
+    for i in 0..5 {
+        // Generate a seed
+        let mut seed = [0u8; 128];
+        rand::thread_rng().fill_bytes(&mut seed);
+
+        // Mint NFT
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, XRD, dec!(0))
+            .take_all_from_worktop(XRD, "payment")
+            .call_method_with_name_lookup(component, "mint_nft", |lookup| {
+                manifest_args!(seed.to_vec(), lookup.bucket("payment"))
+            })
+            .deposit_batch(account)
+            .build();
+
+        let receipt = ledger.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        );
+
+        // Print fee cost, with the storage cost separately as that's what the size of the NFT data adds to
+        println!(
+            "#{} execution cost: {} XRD, storage cost: {} XRD, total cost: {} XRD",
+            i + 1,
+            receipt.fee_summary.total_execution_cost_in_xrd,
+            receipt.fee_summary.total_storage_cost_in_xrd,
+            receipt.fee_summary.total_cost()
+        );
+
+        // Assert
+        receipt.expect_commit_success();
+
+        let nft_data = ledger.get_non_fungible_data::<SVGenesisNFT>(
+            nft_resource,
+            NonFungibleLocalId::integer(i + 1),
+        );
+        let (nft_image_data, _) = svgenesis::nft_generator::generate_nft_image_data(&seed.to_vec());
+        assert!(matches!(nft_data.svg_data, SVGData::Deflate(_)));
+        assert!(nft_data.svg_data.len() < nft_image_data.len());
+        assert!(nft_data.svg_data.len() < hex::encode(&nft_image_data).len());
+        assert_eq!(nft_data.svg_data.decode().unwrap(), nft_image_data);
+    }
+}
+
+// Mint a load of NFTs for test/review purposes
+#[test]
+fn mint_nft_batch() -> Result<(), RuntimeError> {
+    // Instantiate the component
+    let mut env = TestEnvironment::new();
+    let package_address =
+        PackageFactory::compile_and_publish(this_package!(), &mut env, CompileProfile::Fast)?;
+
+    let (mut svgenesis, _) = SVGenesis::instantiate(
+        None,
+        None,
+        None,
+        StorageMode::Full,
+        package_address,
+        &mut env,
+    )?;
+
+    // Disable auth and limits so we don't run into unnecessary issues here
+    env.disable_auth_module();
+    env.disable_limits_module();
+
+    // Create images directory if necessary
+    let _ = fs::create_dir_all("test_images");
+
+    // Mint X NFTs and write them to disk
+    for i in 1..1001 {
+        // Generate a seed
+        let mut data = [0u8; 128];
+        rand::thread_rng().fill_bytes(&mut data);
+
+        // Mint the NFT
+        let payment = xrd_bucket(dec!(0), &mut env)?;
+        let (nft_bucket, _) = svgenesis.mint_nft(data.to_vec(), payment, &mut env)?;
+
+        // Get the NFT data
+        let resource_manager = ResourceManager(nft_bucket.resource_address(&mut env)?);
+        let nft_data = resource_manager.get_non_fungible_data::<_, _, SVGenesisNFT>(
+            nft_bucket
+                .non_fungible_local_ids(&mut env)?
+                .first()
+                .unwrap()
+                .clone(),
+            &mut env,
+        )?;
+
+        println!("{:?}: {:#?}", nft_data.name, nft_data.layers);
+
+        let svg_data = nft_data.svg_data.decode().unwrap();
+
+        // Write to disk
+        fs::write(format!("test_images/{i}.svg"), svg_data).expect("Failed to write SVG file.");
+    }
+
+    Ok(())
+}
+
+// Build a manifest that mints 10 NFTs for testing on Stonknet
+#[test]
+fn build_mint_manifest() -> Result<(), RuntimeError> {
+    let account_address = GlobalAddress::try_from_bech32(
+        &AddressBech32Decoder::new(&NetworkDefinition::stokenet()),
+        "account_tdx_2_1292jyxrlexx6m877v038jmyjs0cna83l3suppctuy257x5a4unjqds",
+    )
+    .unwrap();
+
+    let component_address = GlobalAddress::try_from_bech32(
+        &AddressBech32Decoder::new(&NetworkDefinition::stokenet()),
+        "component_tdx_2_1cz7tlcgchcmruknrwq5t42atcvkdgyfjm7w40vn24xn22j2e2w7sz7",
+    )
+    .unwrap();
+
+    // Generate the seeds to use
+    let seeds: Vec<Vec<u8>> = (0..10)
+        .map(|_| {
+            let mut data = [0u8; 128];
+            rand::thread_rng().fill_bytes(&mut data);
+            data.to_vec()
+        })
+        .collect();
+
+    // Generate the mint instruction, taking an (empty) payment bucket since the mint is free, and deposit the minted NFTs
+    let manifest = ManifestBuilder::new()
+        .call_method(account_address, "withdraw", manifest_args!(XRD, dec!(0)))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(component_address, "mint_nfts", |lookup| {
+            manifest_args!(seeds.clone(), lookup.bucket("payment"))
+        })
+        .deposit_batch(account_address);
+
+    // Write the manifest to disk
+    let _ = dump_manifest_to_file_system(
+        manifest.object_names(),
+        &manifest.build(),
+        "./manifests",
+        Some("mint_manifest"),
+        &NetworkDefinition::stokenet(),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn layer_ids_are_unique_and_categorized() {
+    // Arrange
+    let categories = [
+        (background_layers(), LayerCategory::Background),
+        (frame_layers(), LayerCategory::Frame),
+        (big_element_layers(), LayerCategory::BigElement),
+        (overlay_layers(), LayerCategory::Overlay),
+        (small_element_layers(), LayerCategory::SmallElement),
+    ];
+
+    // Act
+    let ids: Vec<&str> = all_layers().iter().map(|(layer, _)| layer.id()).collect();
+    let unique_ids: std::collections::HashSet<&str> = ids.iter().copied().collect();
+
+    // Assert
+    assert_eq!(ids.len(), unique_ids.len());
+
+    for (layers, category) in categories {
+        for (layer, _) in layers {
+            assert_eq!(layer.category(), category);
+            assert_eq!(layer.layer_name(), layer.id());
+
+            // Exclusions must refer to existing layers
+            for exclusion in layer.exclusions() {
+                assert!(
+                    all_layers()
+                        .iter()
+                        .any(|(other_layer, _)| exclusion.excludes(other_layer.as_ref())),
+                    "{} has an exclusion that matches no layer: {:?}",
+                    layer.id(),
+                    exclusion
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn exclusions_can_target_categories_and_tags() {
+    // Arrange
+    let frame_exclusions = FrameStraight.exclusions();
+    let pill_ball_exclusions = BigElementPillBall.exclusions();
 
-        println!("{:?}: {:#?}", nft_data.name, nft_data.layers);
+    // Act
+    let excluded_by = |exclusions: &Vec<Exclusion>, layer: &dyn Layer| {
+        exclusions.iter().any(|exclusion| exclusion.excludes(layer))
+    };
 
-        let svg_data = hex::decode(nft_data.svg_data).unwrap();
+    // Assert
+    assert!(excluded_by(&frame_exclusions, &BigElementSquare));
+    assert!(!excluded_by(&frame_exclusions, &BigElementPillBall));
+    assert!(small_element_layers()
+        .iter()
+        .all(|(layer, _)| excluded_by(&pill_ball_exclusions, layer.as_ref())));
+    assert!(!excluded_by(&pill_ball_exclusions, &OverlayDiamond));
+}
 
-        // Write to disk
-        fs::write(
-            format!("test_images/{i}.svg"),
-            svg_data,
-        )
-        .expect("Failed to write SVG file.");
+#[test]
+fn fingerprint_ignores_generated_ids() {
+    // Arrange
+    let layers = vec!["BackgroundPlain".to_string()];
+    let image = r#"<svg viewBox="0 0 1000 1000"><defs><linearGradient id="gr123"><stop stop-color="hsla(10,50%,50%,1)"/></linearGradient></defs><rect fill="url(#gr123)"/></svg>"#;
+    let same_image_other_id = image.replace("gr123", "gr4567");
+    let other_color = image.replace("hsla(10,", "hsla(20,");
+
+    // Act
+    let fingerprint = canonical_fingerprint(image, &layers);
+
+    // Assert
+    assert_eq!(
+        fingerprint,
+        canonical_fingerprint(&same_image_other_id, &layers)
+    );
+    assert_ne!(fingerprint, canonical_fingerprint(&other_color, &layers));
+    assert_ne!(
+        fingerprint,
+        canonical_fingerprint(image, &["BackgroundLinearGradient".to_string()])
+    );
+}
+
+#[test]
+fn default_generator_config_matches_v1() {
+    for (index, seed) in golden_seeds().iter().enumerate() {
+        // Act
+        let image =
+            generate_nft_image_data_with_config(seed, &GeneratorConfig::default(), &DefaultWeights);
+
+        // Assert
+        assert_eq!(
+            image,
+            v1_generator::nft_generator::generate_nft_image_data(seed),
+            "The default generator config doesn't match the original generator for golden seed {index}"
+        );
     }
+}
 
-    Ok(())
+#[test]
+fn custom_generator_config_follows_its_stages() {
+    // Arrange
+    let config = GeneratorConfig {
+        base_color: None,
+        stages: vec![
+            Stage::new(LayerCategory::Background, Chance::Always),
+            Stage::new(LayerCategory::Frame, Chance::Always)
+                .with_condition(Condition::HasBaseColor),
+            Stage::new(LayerCategory::SmallElement, Chance::Always).with_count(2, 3),
+        ],
+        min_layers: 1,
+        random_streams: RandomStreams::Shared,
+        id_allocation: IdAllocation::Random,
+        minify: false,
+    };
+    let categories: std::collections::HashMap<String, LayerCategory> = all_layers()
+        .iter()
+        .map(|(layer, _)| (layer.id().to_string(), layer.category()))
+        .collect();
+
+    for seed in golden_seeds() {
+        // Act
+        let (_, layers) = generate_nft_image_data_with_config(&seed, &config, &DefaultWeights);
+
+        // Assert
+        let layer_categories: Vec<LayerCategory> =
+            layers.iter().map(|layer| categories[layer]).collect();
+        assert_eq!(layer_categories[0], LayerCategory::Background);
+        assert!(!layer_categories.contains(&LayerCategory::Frame));
+        assert!((2..=3).contains(&layer_categories[1..].len()));
+        assert!(layer_categories[1..]
+            .iter()
+            .all(|category| *category == LayerCategory::SmallElement));
+    }
 }
 
-// Build a manifest that mints 10 NFTs for testing on Stonknet
 #[test]
-fn build_mint_manifest() -> Result<(), RuntimeError> {
-    let mut manifest = ManifestBuilder::new();
+fn composition_renders_to_generated_image() {
+    for (index, seed) in golden_seeds().iter().enumerate() {
+        // Act
+        let composition =
+            compose_nft_image_with_version(seed, GeneratorVersion::V1, &DefaultWeights);
 
-    // Generate the mint instructions
-    for _ in 0..10 {
-        // Generate a seed to use
-        let mut data = [0u8; 128];
-        rand::thread_rng().fill_bytes(&mut data);
+        // Assert
+        assert_eq!(
+            (
+                renderer::render(&composition).to_string(),
+                composition.layer_names()
+            ),
+            v1_generator::nft_generator::generate_nft_image_data(seed),
+            "The composition doesn't render to the image of the original generator for golden seed {index}"
+        );
+    }
+}
 
-        let component_address = GlobalAddress::try_from_bech32(
-            &AddressBech32Decoder::new(&NetworkDefinition::stokenet()),
-            "component_tdx_2_1cz7tlcgchcmruknrwq5t42atcvkdgyfjm7w40vn24xn22j2e2w7sz7",
+#[test]
+fn composition_keeps_definitions_in_document_order() {
+    // Arrange
+    let gradient = |id: &str, hue: i16| {
+        LinearGradient::new().set("id", id).add(
+            Stop::new()
+                .set("offset", "0%")
+                .set("stop-color", HSL::new(hue, 50, 50, 100).as_string()),
         )
-        .unwrap();
+    };
+    let rectangle = |fill: &str| {
+        Element::from(
+            Rectangle::new()
+                .set("width", "100%")
+                .set("height", "100%")
+                .set("fill", fill),
+        )
+    };
+    // The first layer defines a gradient no shape refers to, the second one reuses the id of a gradient
+    let generated_layers: Vec<(Box<dyn Layer>, Vec<Element>)> = vec![
+        (
+            Box::new(BigElementSquare),
+            vec![
+                Definitions::new()
+                    .add(gradient("unused", 10))
+                    .add(gradient("gr1", 20))
+                    .into(),
+                rectangle("url(#gr1)"),
+            ],
+        ),
+        (
+            Box::new(FrameStraight),
+            vec![
+                Definitions::new()
+                    .add(Definitions::new().add(gradient("gr1", 30)))
+                    .into(),
+                rectangle("url(#gr1)"),
+            ],
+        ),
+    ];
+
+    // Render it like v1 did before compositions: the children of all <defs> elements in a single <defs>, then the
+    // other elements
+    let mut defs = Definitions::new();
+    let mut document = Document::new().set("viewBox", (0, 0, 1000, 1000));
+    for element in generated_layers.iter().flat_map(|(_, elements)| elements) {
+        if element.get_name() == "defs" {
+            for definition in element.get_children() {
+                defs.append(definition.to_owned());
+            }
+        }
+    }
+    document.append(defs);
+    for element in generated_layers.iter().flat_map(|(_, elements)| elements) {
+        if element.get_name() != "defs" {
+            document.append(element.clone());
+        }
+    }
+
+    // Act
+    let composition = Composition::from_layers(None, generated_layers);
+
+    // Assert
+    let definition_ids: Vec<&str> = composition
+        .definitions
+        .iter()
+        .filter_map(Paint::definition_id)
+        .collect();
+    assert_eq!(definition_ids, vec!["unused", "gr1", "gr1"]);
+    for layer in &composition.layers {
+        assert_eq!(
+            layer.shapes[0].fill,
+            Some(composition.definitions[1].clone())
+        );
+    }
+    assert_eq!(
+        renderer::render(&composition).to_string(),
+        document.to_string()
+    );
+}
+
+#[test]
+fn composition_describes_layers_without_parsing_svg() {
+    // Arrange
+    let seed = hash("svgenesis-composition").0.repeat(4);
+    let mut random = Random::new(&seed);
+    let elements = BigElementSquare.generate(
+        &mut random,
+        &None,
+        &mut IdAllocator::new(IdAllocation::Random),
+    );
+
+    // Act
+    let composition = Composition::from_layers(None, vec![(Box::new(BigElementSquare), elements)]);
+
+    // Assert
+    let layer = &composition.layers[0];
+    assert_eq!(layer.id, BigElementSquare.id());
+    assert_eq!(layer.category, LayerCategory::BigElement);
+    assert_eq!(layer.shapes.len(), 1);
+
+    let square = &layer.shapes[0];
+    assert_eq!(square.kind, ShapeKind::Rectangle);
+    assert_eq!(square.attributes["width"], square.attributes["height"]);
+    assert!(
+        square.transforms.is_empty()
+            || square.transforms
+                == vec![Transform::Rotate {
+                    degrees: 45,
+                    center: Some((500, 500)),
+                }]
+    );
+    match &square.fill {
+        Some(Paint::Color(_)) => {}
+        Some(Paint::LinearGradient(gradient)) => {
+            assert_eq!(gradient.angle, Some(45));
+            assert_eq!(gradient.stops.len(), 2);
+        }
+        fill => panic!("Unexpected fill {fill:?}"),
+    }
+}
 
-        manifest =
-            manifest.call_method(component_address, "mint_nft", manifest_args!(data.to_vec()));
+#[test]
+fn traits_describe_every_layer() {
+    for seed in golden_seeds() {
+        // Act
+        let composition =
+            compose_nft_image_with_version(&seed, GeneratorVersion::V1, &DefaultWeights);
+        let traits = composition.traits();
+
+        // Assert
+        assert_eq!(traits.len(), composition.layers.len());
+        for (layer_traits, layer) in traits.iter().zip(&composition.layers) {
+            assert_eq!(layer_traits.category, layer.category.display_name());
+            assert_eq!(layer_traits.name, layer.name);
+            assert!(layer_traits
+                .parameters
+                .iter()
+                .any(|(name, _)| name == "Fill"));
+            assert!(!layer_traits.colors.is_empty() && layer_traits.colors.len() <= 2);
+        }
+    }
+}
+
+#[test]
+fn layers_and_colors_have_readable_names() {
+    // Assert
+    assert_eq!(LayerCategory::SmallElement.display_name(), "Small Element");
+    assert_eq!(BigElementPillBall.display_name(), "Pill Ball");
+    assert_eq!(HSL::new(220, 90, 60, 100).color_name(), "Blue");
+    assert_eq!(HSL::new(0, 90, 80, 100).color_name(), "Light Red");
+    assert_eq!(HSL::new(120, 5, 50, 100).color_name(), "Gray");
+    assert_eq!(HSL::new(120, 90, 98, 100).color_name(), "White");
+}
+
+#[test]
+fn random_streams_keep_other_layers_stable() {
+    // Arrange
+    let config = GeneratorConfig {
+        base_color: None,
+        stages: vec![
+            Stage::new(LayerCategory::Background, Chance::Always),
+            Stage::new(LayerCategory::SmallElement, Chance::Always),
+        ],
+        min_layers: 1,
+        random_streams: RandomStreams::PerLabel,
+        id_allocation: IdAllocation::Random,
+        minify: false,
+    };
+    let mut config_with_frame = config.clone();
+    config_with_frame
+        .stages
+        .insert(1, Stage::new(LayerCategory::Frame, Chance::Always));
+
+    for seed in golden_seeds() {
+        // Act
+        let composition = compose_nft_image_with_config(&seed, &config, &DefaultWeights);
+        let composition_with_frame =
+            compose_nft_image_with_config(&seed, &config_with_frame, &DefaultWeights);
+
+        // Assert
+        let layers_without_frame: Vec<_> = composition_with_frame
+            .layers
+            .into_iter()
+            .filter(|layer| layer.category != LayerCategory::Frame)
+            .collect();
+        assert_eq!(layers_without_frame, composition.layers);
+    }
+}
+
+#[test]
+fn derived_seeds_depend_on_seed_and_label() {
+    // Arrange
+    let seed = golden_seeds().remove(0);
+    let other_seed = golden_seeds().remove(1);
+
+    // A long seed and the same seed with a different last byte
+    let mut long_seed = [0u8; 2048];
+    rand::thread_rng().fill_bytes(&mut long_seed);
+    let mut other_long_seed = long_seed;
+    other_long_seed[2047] ^= 0xff;
+
+    // Act
+    let derived_seed = derive_seed(&seed, "layer/BackgroundRectangle#0");
+
+    // Assert
+    assert_eq!(
+        derived_seed,
+        derive_seed(&seed, "layer/BackgroundRectangle#0")
+    );
+    assert_ne!(
+        derived_seed,
+        derive_seed(&seed, "layer/BackgroundRectangle#1")
+    );
+    assert_ne!(
+        derived_seed,
+        derive_seed(&other_seed, "layer/BackgroundRectangle#0")
+    );
+    assert_ne!(
+        derive_seed(&long_seed, "layer/BackgroundRectangle#0"),
+        derive_seed(&other_long_seed, "layer/BackgroundRectangle#0")
+    );
+}
+
+#[test]
+fn data_uris_are_small_and_render() {
+    for generator_version in GeneratorVersion::ALL {
+        for seed in golden_seeds() {
+            // Arrange
+            let (nft_image_data, _) =
+                generate_nft_image_data_with_version(&seed, generator_version, &DefaultWeights);
+
+            // Act
+            let data_uri = svg_data_uri(&nft_image_data);
+            let percent_encoded_data_uri = percent_encoded_svg_data_uri(&nft_image_data);
+            let base64_data_uri = base64_svg_data_uri(&nft_image_data);
+
+            // Assert
+            assert!(data_uri.len() <= percent_encoded_data_uri.len());
+            assert!(data_uri.len() <= base64_data_uri.len());
+            assert!(data_uri.len() <= KEY_IMAGE_URL_BUDGET);
+            assert_eq!(decode_svg_data_uri(&data_uri).unwrap(), nft_image_data);
+
+            let pixels = rasterize(&nft_image_data);
+            assert!(
+                rasterize_data_uri(&percent_encoded_data_uri) == pixels,
+                "Percent-encoded data URI of {} looks different",
+                hex::encode(&seed)
+            );
+            assert!(
+                rasterize_data_uri(&base64_data_uri) == pixels,
+                "Base64 data URI of {} looks different",
+                hex::encode(&seed)
+            );
+        }
     }
+}
+
+#[test]
+fn data_uris_escape_special_characters() {
+    // Arrange
+    // Colors with #, percentages, both kinds of quotes and non-ASCII text
+    let svg_data = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1000 1000"><title>Genèse "100%" d'SVG ✨</title><defs><linearGradient id="gr1"><stop offset="0%" stop-color="hsla(10,50%,50%,1)"/><stop offset="100%" stop-color='#00f'/></linearGradient></defs><rect width="1000" height="500" fill="url(#gr1)"/><rect y="500" width="1000" height="500" fill='#ff0000'/></svg>"##;
+
+    // Act
+    let percent_encoded_data_uri = percent_encoded_svg_data_uri(svg_data);
+    let base64_data_uri = base64_svg_data_uri(svg_data);
+
+    // Assert
+    let pixels = rasterize(svg_data);
+    assert!(pixels.iter().any(|channel| *channel != 0));
+    assert!(rasterize_data_uri(&percent_encoded_data_uri) == pixels);
+    assert!(rasterize_data_uri(&base64_data_uri) == pixels);
+    assert!(
+        rasterize_data_uri(&percent_encoded_svg_data_uri(
+            &svg_data.replace("#ff0000", "#00ff00")
+        )) != pixels
+    );
 
-    // Deposit the minted NFTs
-    manifest = manifest.deposit_batch(
-        GlobalAddress::try_from_bech32(
-            &AddressBech32Decoder::new(&NetworkDefinition::stokenet()),
-            "account_tdx_2_1292jyxrlexx6m877v038jmyjs0cna83l3suppctuy257x5a4unjqds",
+    assert_eq!(
+        decode_svg_data_uri(&percent_encoded_data_uri).unwrap(),
+        svg_data
+    );
+    assert_eq!(decode_svg_data_uri(&base64_data_uri).unwrap(), svg_data);
+
+    let svg_data = "<svg>#%é'\"</svg>";
+    assert_eq!(
+        percent_encoded_svg_data_uri(svg_data),
+        "data:image/svg+xml,%3Csvg%3E%23%25%C3%A9%27%22%3C/svg%3E"
+    );
+    assert_eq!(
+        base64_svg_data_uri(svg_data),
+        "data:image/svg+xml;base64,PHN2Zz4jJcOpJyI8L3N2Zz4="
+    );
+    assert_eq!(
+        decode_svg_data_uri(
+            "data:image/svg+xml,%3Csvg%20viewBox%3D%220%200%201%201%22%3E%3C%2Fsvg%3E"
         )
         .unwrap(),
+        "<svg viewBox=\"0 0 1 1\"></svg>"
     );
+}
 
-    // Write the manifest to disk
-    let _ = dump_manifest_to_file_system(
-        manifest.object_names(),
-        &manifest.build(),
-        "./manifests",
-        Some("mint_manifest"),
-        &NetworkDefinition::stokenet(),
+#[test]
+fn svg_data_is_compressed_and_decodes() {
+    for seed in golden_seeds() {
+        // Arrange
+        let (nft_image_data, _) =
+            generate_nft_image_data_with_version(&seed, GeneratorVersion::CURRENT, &DefaultWeights);
+
+        // Act
+        let svg_data = SVGData::encode(&nft_image_data);
+
+        // Assert
+        assert!(matches!(svg_data, SVGData::Deflate(_)));
+        assert!(svg_data.len() < nft_image_data.len());
+        assert_eq!(svg_data.decode().unwrap(), nft_image_data);
+        assert_eq!(
+            SVGData::from_hex(&hex::encode(&nft_image_data)),
+            Some(svg_data)
+        );
+    }
+
+    assert_eq!(SVGData::encode("<svg/>"), SVGData::Raw(b"<svg/>".to_vec()));
+    assert_eq!(SVGData::Deflate(vec![0xff; 8]).decode(), None);
+    assert!(SVGData::from_hex("").unwrap().is_empty());
+}
+
+#[test]
+fn minified_images_render_to_the_same_pixels() {
+    for generator_version in GeneratorVersion::ALL {
+        for seed in golden_seeds() {
+            // Arrange
+            let composition =
+                compose_nft_image_with_version(&seed, generator_version, &DefaultWeights);
+
+            // Act
+            let nft_image_data = renderer::render(&composition).to_string();
+            let minified_nft_image_data = renderer::render_minified(&composition).to_string();
+
+            // Assert
+            assert!(minified_nft_image_data.len() <= nft_image_data.len());
+            assert!(
+                rasterize(&minified_nft_image_data) == rasterize(&nft_image_data),
+                "Minified image of {} looks different",
+                hex::encode(&seed)
+            );
+        }
+    }
+}
+
+#[test]
+fn minified_images_merge_identical_gradients() {
+    // Arrange
+    let gradient = |id: &str| Gradient {
+        id: id.to_string(),
+        stops: vec![
+            GradientStop {
+                offset: "0%".to_string(),
+                color: HSL::new(10, 20, 30, 100),
+            },
+            GradientStop {
+                offset: "100%".to_string(),
+                color: HSL::new(40, 50, 60, 50),
+            },
+        ],
+        angle: Some(0),
+        attributes: std::collections::BTreeMap::new(),
+        nested: true,
+    };
+    let shape = |id: &str| Shape {
+        kind: ShapeKind::Rectangle,
+        attributes: std::collections::BTreeMap::from([
+            ("width".to_string(), "1000".to_string()),
+            ("height".to_string(), "1000".to_string()),
+            ("x".to_string(), "0".to_string()),
+        ]),
+        transforms: vec![Transform::Rotate {
+            degrees: 0,
+            center: Some((500, 500)),
+        }],
+        fill: Some(Paint::LinearGradient(gradient(id))),
+        stroke: None,
+    };
+    let composition = Composition {
+        base_color: None,
+        layers: vec![ComposedLayer {
+            id: BigElementSquare.id().to_string(),
+            name: BigElementSquare.display_name(),
+            category: LayerCategory::BigElement,
+            shapes: vec![shape("gr1"), shape("gr2")],
+        }],
+        definitions: vec![
+            Paint::LinearGradient(gradient("gr1")),
+            Paint::LinearGradient(gradient("gr2")),
+        ],
+    };
+
+    // Act
+    let minified_nft_image_data = renderer::render_minified(&composition).to_string();
+
+    // Assert
+    assert_eq!(
+        minified_nft_image_data.matches("<linearGradient").count(),
+        1
     );
+    assert_eq!(minified_nft_image_data.matches("url(#gr1)").count(), 2);
+    assert!(!minified_nft_image_data.contains("gr2"));
+    assert!(!minified_nft_image_data.contains("<defs>\n<defs>"));
+    assert!(!minified_nft_image_data.contains("rotate("));
+    assert!(!minified_nft_image_data.contains(" x="));
+    assert!(minified_nft_image_data.contains("stop-color=\"hsl(10,20%,30%)\""));
+    assert!(minified_nft_image_data.contains("stop-color=\"hsla(40,50%,60%,.5)\""));
+}
 
-    Ok(())
+#[test]
+fn every_reference_points_to_a_unique_definition() {
+    for generator_version in GeneratorVersion::ALL {
+        for seed in golden_seeds() {
+            // Act
+            let (nft_image_data, _) =
+                generate_nft_image_data_with_version(&seed, generator_version, &DefaultWeights);
+
+            // Assert
+            let ids = definition_ids(&nft_image_data);
+            let unique_ids: std::collections::HashSet<&str> = ids.iter().copied().collect();
+            for reference in nft_image_data.split("url(#").skip(1) {
+                let id = &reference[..reference.find(')').unwrap()];
+                assert!(unique_ids.contains(id), "url(#{id}) has no definition");
+            }
+
+            if generator_version == GeneratorVersion::V2 {
+                assert_eq!(unique_ids.len(), ids.len());
+                assert!(ids.iter().all(|id| is_sequential_id(id)));
+            }
+        }
+    }
+}
+
+#[test]
+fn current_generator_version_is_frozen() {
+    // Assert
+    assert!(GeneratorVersion::CURRENT.is_frozen());
+    assert_eq!(
+        GeneratorVersion::from_u32(svgenesis::nft_generator::GENERATOR_VERSION),
+        Some(GeneratorVersion::CURRENT)
+    );
+}
+
+#[test]
+fn cannot_render_unknown_generator_version() {
+    // Arrange
+    let seed = golden_seeds().remove(0);
+    let unknown_version = GeneratorVersion::ALL.last().unwrap().as_u32() + 1;
+
+    // Act
+    let image = svgenesis::nft_generator::render_nft_image(&seed, unknown_version);
+
+    // Assert
+    assert_eq!(image, None);
+}
+
+#[test]
+fn v1_matches_the_original_generator() {
+    // Arrange
+    // The golden seeds and a batch of random seeds of different lengths
+    let mut seeds = golden_seeds();
+    for _ in 0..256 {
+        let mut seed = vec![0u8; 4 * rand::thread_rng().gen_range(1..=64)];
+        rand::thread_rng().fill_bytes(&mut seed);
+        seeds.push(seed);
+    }
+
+    for seed in seeds {
+        // Act
+        let image =
+            generate_nft_image_data_with_version(&seed, GeneratorVersion::V1, &DefaultWeights);
+
+        // Assert
+        assert_eq!(
+            image,
+            v1_generator::nft_generator::generate_nft_image_data(&seed),
+            "Generator v1 changed its output for seed {}",
+            hex::encode(&seed)
+        );
+    }
+}
+
+#[test]
+fn frozen_generator_versions_match_golden_files() {
+    // Set SVGENESIS_BLESS=1 to record missing golden files. Existing golden files are never overwritten.
+    let bless = std::env::var("SVGENESIS_BLESS").is_ok();
+
+    // V1 is checked against the original generator instead, see v1_matches_the_original_generator
+    for generator_version in GeneratorVersion::ALL
+        .into_iter()
+        .filter(|generator_version| {
+            generator_version.is_frozen() && *generator_version != GeneratorVersion::V1
+        })
+    {
+        for (index, seed) in golden_seeds().iter().enumerate() {
+            // Arrange
+            let golden_path = golden_path(generator_version, index);
+
+            // Act
+            let (svg_data, layers) =
+                generate_nft_image_data_with_version(seed, generator_version, &DefaultWeights);
+            let output = golden_output(&svg_data, &layers);
+
+            // Assert
+            if bless && !std::path::Path::new(&golden_path).exists() {
+                fs::create_dir_all(std::path::Path::new(&golden_path).parent().unwrap()).unwrap();
+                fs::write(&golden_path, output).unwrap();
+            } else {
+                assert_eq!(
+                    output,
+                    read_golden_file(generator_version, index),
+                    "Generator v{} changed its output for golden seed {}",
+                    generator_version.as_u32(),
+                    index
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn generator_does_not_depend_on_scrypto() {
+    // Act
+    // With the cli and wasm features, so the check covers every dependency besides the blueprint
+    let output = std::process::Command::new(env!("CARGO"))
+        .args([
+            "tree",
+            "--manifest-path",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"),
+            "--no-default-features",
+            "--features",
+            "cli,wasm",
+            "--edges",
+            "normal,build",
+            "--prefix",
+            "none",
+        ])
+        .output()
+        .unwrap();
+
+    // Assert
+    assert!(
+        output.status.success(),
+        "cargo tree failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let dependencies = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        !dependencies
+            .lines()
+            .any(|dependency| dependency.starts_with("scrypto")),
+        "The generator depends on scrypto without the blueprint feature:\n{dependencies}"
+    );
+}
+
+/// Renders SVG data to pixels, like browsers and wallets would
+fn rasterize(nft_image_data: &str) -> Vec<u8> {
+    let tree =
+        resvg::usvg::Tree::from_str(nft_image_data, &resvg::usvg::Options::default()).unwrap();
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(500, 500).unwrap();
+    resvg::render(
+        &tree,
+        resvg::tiny_skia::Transform::from_scale(0.5, 0.5),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap.take()
+}
+
+/// Renders a data URI to pixels by loading it as an image, so resvg decodes it the way browsers do
+fn rasterize_data_uri(data_uri: &str) -> Vec<u8> {
+    rasterize(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 1000 1000\"><image width=\"1000\" height=\"1000\" href=\"{}\"/></svg>",
+        data_uri.replace('&', "&amp;").replace('"', "&quot;")
+    ))
+}
+
+// The maximum size of a key_image_url, so the NFT data of an image stays cheap to store
+const KEY_IMAGE_URL_BUDGET: usize = 3 * 1024;
+
+// A fixed set of seeds for the golden tests, derived from hashes so they cover a wide range of layers
+fn golden_seeds() -> Vec<Vec<u8>> {
+    (0..32)
+        .map(|index| {
+            let seed_hash = hash(format!("svgenesis-golden-{index}"));
+
+            seed_hash.0.repeat(4)
+        })
+        .collect()
+}
+
+// The golden file of a generator version for the golden seed at the index
+fn golden_path(generator_version: GeneratorVersion, index: usize) -> String {
+    format!(
+        "{}/tests/assets/golden/v{}/{index:02}.svg",
+        env!("CARGO_MANIFEST_DIR"),
+        generator_version.as_u32()
+    )
+}
+
+// The ids of the definitions in SVG data, in order of appearance
+fn definition_ids(nft_image_data: &str) -> Vec<&str> {
+    nft_image_data
+        .split("id=\"")
+        .skip(1)
+        .map(|rest| &rest[..rest.find('"').unwrap()])
+        .collect()
+}
+
+// Whether an id was allocated by IdAllocation::Sequential, like g0, f1 or p2
+fn is_sequential_id(id: &str) -> bool {
+    id.len() > 1 && ["g", "f", "p"].contains(&&id[..1]) && id[1..].parse::<u32>().is_ok()
+}
+
+// The contents of a golden file: the layer names in a comment, followed by the SVG data
+fn golden_output(svg_data: &str, layers: &[String]) -> String {
+    format!("<!-- {} -->\n{}", layers.join(","), svg_data)
+}
+
+// Reads a golden file, failing with instructions if it's missing
+fn read_golden_file(generator_version: GeneratorVersion, index: usize) -> String {
+    let golden_path = golden_path(generator_version, index);
+
+    fs::read_to_string(&golden_path).unwrap_or_else(|_| {
+        panic!(
+            "Missing golden file {golden_path}. The golden files of a frozen version are committed with it, so restore \
+            it from git. Only record it with SVGENESIS_BLESS=1 if the output of generator v{} is known to be unchanged.",
+            generator_version.as_u32()
+        )
+    })
+}
+
+// Returns layer weights and two different seeds that result in the same image with those weights. Every seed rolls
+// from its own Blake2b streams, so the weights only leave BackgroundRectangle as background and disable the big elements
+// to make the images simple enough to collide.
+fn seeds_with_same_image() -> (std::collections::HashMap<String, u32>, Vec<u8>, Vec<u8>) {
+    let weights: std::collections::HashMap<String, u32> = background_layers()
+        .into_iter()
+        .chain(big_element_layers())
+        .map(|(layer, _)| layer.layer_name())
+        .filter(|layer_name| layer_name != "BackgroundRectangle")
+        .map(|layer_name| (layer_name, 0))
+        .collect();
+    let seed = 13676u32.to_le_bytes().to_vec();
+    let other_seed = 14953u32.to_le_bytes().to_vec();
+
+    let (nft_image_data, layers) =
+        generate_nft_image_data_with_version(&seed, GeneratorVersion::CURRENT, &weights);
+    let (other_nft_image_data, other_layers) =
+        generate_nft_image_data_with_version(&other_seed, GeneratorVersion::CURRENT, &weights);
+    assert_eq!(
+        canonical_fingerprint(&nft_image_data, &layers),
+        canonical_fingerprint(&other_nft_image_data, &other_layers),
+        "The seeds no longer result in the same image with the current generator version"
+    );
+
+    (weights, seed, other_seed)
+}
+
+// Publishes the package and the mock RandomComponent, and instantiates a component that uses the mock for its randomness
+//
+// Returns the ledger, the public key and account that own the component, the component, the mock RandomComponent and
+// the NFT resource
+fn instantiate_with_mock_random() -> (
+    DefaultLedgerSimulator,
+    Secp256k1PublicKey,
+    ComponentAddress,
+    ComponentAddress,
+    ComponentAddress,
+    ResourceAddress,
+) {
+    let mut ledger = LedgerSimulatorBuilder::new().without_kernel_trace().build();
+    let (public_key, _private_key, account) = ledger.new_allocated_account();
+
+    let package_address = ledger.compile_and_publish(this_package!());
+    let mock_package_address = ledger.compile_and_publish(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/mock_random"
+    ));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            mock_package_address,
+            "MockRandom",
+            "instantiate",
+            manifest_args!(),
+        )
+        .build();
+
+    let receipt = ledger.execute_manifest(manifest, vec![]);
+    let random_component = receipt.expect_commit_success().new_component_addresses()[0];
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "SVGenesis",
+            "instantiate",
+            manifest_args!(
+                None::<Decimal>,
+                None::<u64>,
+                Some(random_component),
+                StorageMode::Full
+            ),
+        )
+        .deposit_batch(account)
+        .build();
+
+    let receipt = ledger.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    let component = receipt.expect_commit_success().new_component_addresses()[0];
+    let nft_resource = nft_resource(&receipt);
+
+    (
+        ledger,
+        public_key,
+        account,
+        component,
+        random_component,
+        nft_resource,
+    )
+}
+
+// Returns the NFT resource that instantiating the component created. The badges are fungible, so it's the only
+// non-fungible resource of the receipt.
+fn nft_resource(receipt: &TransactionReceipt) -> ResourceAddress {
+    *receipt
+        .expect_commit_success()
+        .new_resource_addresses()
+        .iter()
+        .find(|resource_address| !resource_address.is_fungible())
+        .unwrap()
+}
+
+// Checks that the transaction failed with the provided SVGenesisError
+fn assert_receipt_svgenesis_error(receipt: &TransactionReceipt, error: SVGenesisError) {
+    receipt.expect_specific_failure(|runtime_error| match runtime_error {
+        RuntimeError::ApplicationError(ApplicationError::PanicMessage(message)) => {
+            message.contains(&error.to_string())
+        }
+        _ => false,
+    });
+}
+
+// Checks that the result failed with the provided SVGenesisError
+fn assert_svgenesis_error<T>(result: Result<T, RuntimeError>, error: SVGenesisError) {
+    match result.err() {
+        Some(RuntimeError::ApplicationError(ApplicationError::PanicMessage(message))) => assert!(
+            message.contains(&error.to_string()),
+            "Expected {:?}, but got: {}",
+            error,
+            message
+        ),
+        other => panic!("Expected {:?}, but got: {:?}", error, other),
+    }
+}
+
+// Creates a bucket of XRD to pay for mints with
+fn xrd_bucket(
+    amount: Decimal,
+    env: &mut TestEnvironment<InMemorySubstateDatabase>,
+) -> Result<Bucket, RuntimeError> {
+    BucketFactory::create_fungible_bucket(XRD, amount, CreationStrategy::Mock, env)
 }
@@ -0,0 +1,71 @@
+// Calls the `preview` export compiled to WebAssembly, the way a website does. These tests only exist on wasm32:
+// cargo test --target wasm32-unknown-unknown --no-default-features --features wasm --test wasm
+#![cfg(target_arch = "wasm32")]
+
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use svgenesis::nft_generator::GENERATOR_VERSION;
+use svgenesis::preview::preview_wasm;
+use wasm_bindgen::{JsCast, JsError, JsValue};
+use wasm_bindgen_test::*;
+
+// The golden files of the generator version the preview uses, see frozen_generator_versions_match_golden_files
+const GOLDEN_VERSION: u32 = 2;
+const GOLDEN_FILES: [&str; 4] = [
+    include_str!("assets/golden/v2/00.svg"),
+    include_str!("assets/golden/v2/01.svg"),
+    include_str!("assets/golden/v2/02.svg"),
+    include_str!("assets/golden/v2/03.svg"),
+];
+
+#[wasm_bindgen_test]
+fn preview_export_matches_golden_files() {
+    assert_eq!(
+        GENERATOR_VERSION, GOLDEN_VERSION,
+        "The preview uses another generator version, compare it with that version's golden files"
+    );
+
+    for (index, golden_file) in GOLDEN_FILES.iter().enumerate() {
+        // Arrange
+        let seed_hash = Blake2b::<U32>::digest(format!("svgenesis-golden-{index}").as_bytes());
+        let seed_hex = hex::encode(seed_hash.repeat(4));
+
+        // Act
+        let preview = preview_wasm(&seed_hex).unwrap_or_else(|error| panic!("{}", message(error)));
+
+        // Assert
+        assert_eq!(
+            format!("<!-- {} -->\n{}", preview.layers.join(","), preview.svg),
+            *golden_file,
+            "The preview doesn't match the golden file for golden seed {index}"
+        );
+        assert_eq!(preview.hash.len(), 64);
+        assert!(hex::decode(&preview.hash).is_ok());
+    }
+}
+
+#[wasm_bindgen_test]
+fn preview_export_throws_on_invalid_seeds() {
+    // Act
+    let empty_seed = preview_wasm("").unwrap_err();
+    let wrong_length = preview_wasm("abcdef").unwrap_err();
+    let invalid_hex = preview_wasm("xyz0").unwrap_err();
+    let odd_length = preview_wasm("abc").unwrap_err();
+
+    // Assert
+    assert_eq!(message(empty_seed), "Seed can't be empty!");
+    assert_eq!(
+        message(wrong_length),
+        "Seed length must be a multiple of 4!"
+    );
+    assert!(message(invalid_hex).starts_with("Invalid hex"));
+    assert!(message(odd_length).starts_with("Invalid hex"));
+}
+
+// The message of the JavaScript Error that the export throws
+fn message(error: JsError) -> String {
+    JsValue::from(error)
+        .dyn_into::<js_sys::Error>()
+        .unwrap()
+        .message()
+        .into()
+}